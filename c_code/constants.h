@@ -6,6 +6,9 @@
 #include "mpi.h"
 
 const int32_t C_MPI_ERROR_INIT_THREADED = 10000;
+const int32_t C_MPI_ERROR_ALLOCATION = 10001;
+const int32_t C_MPI_ERROR_NAME_TOO_LONG = 10002;
+const int32_t C_MPI_ERROR_MESSAGE_SIZE = 10003;
 
 const int C_MPI_THREAD_OPTIONS[4] = {
     MPI_THREAD_SINGLE,     //  0  only one thread will execute
@@ -14,7 +17,7 @@ const int C_MPI_THREAD_OPTIONS[4] = {
     MPI_THREAD_MULTIPLE,   //  3  if the process is multithreaded, multiple threads may call MPI at once with no restrictions
 };
 
-const MPI_Datatype C_MPI_TYPES[9] = {
+const MPI_Datatype C_MPI_TYPES[17] = {
     MPI_INT32_T,          //  0  i32
     MPI_INT64_T,          //  1  i64
     MPI_UINT32_T,         //  2  u32
@@ -23,20 +26,45 @@ const MPI_Datatype C_MPI_TYPES[9] = {
     MPI_DOUBLE,           //  5  f64
     MPI_C_FLOAT_COMPLEX,  //  6  c32
     MPI_C_DOUBLE_COMPLEX, //  7  c64
-    MPI_BYTE,             //  8  u8
+    MPI_UINT8_T,          //  8  u8
+    MPI_FLOAT_INT,        //  9  (f32, i32) pair
+    MPI_DOUBLE_INT,       // 10  (f64, i32) pair
+    MPI_2INT,             // 11  (i32, i32) pair
+    MPI_LONG_INT,         // 12  (c_long, i32) pair
+    MPI_INT8_T,           // 13  i8
+    MPI_INT16_T,          // 14  i16
+    MPI_UINT16_T,         // 15  u16
+    MPI_C_BOOL,           // 16  bool
 };
 
-const MPI_Op C_MPI_OPS[10] = {
-    MPI_MAX,  //  0  maximum
-    MPI_MIN,  //  1  minimum
-    MPI_SUM,  //  2  sum
-    MPI_PROD, //  3  product
-    MPI_LAND, //  4  logical and
-    MPI_LOR,  //  5  logical or
-    MPI_LXOR, //  6  logical xor
-    MPI_BAND, //  7  bitwise and
-    MPI_BOR,  //  8  bitwise or
-    MPI_BXOR, //  9  bitwise xor
+// derived datatypes have indices greater than or equal to C_MPI_N_BUILTIN_TYPES
+const int32_t C_MPI_N_BUILTIN_TYPES = 17;
+
+const int C_MPI_ORDERS[2] = {
+    MPI_ORDER_C,       //  0  row-major
+    MPI_ORDER_FORTRAN, //  1  column-major
+};
+
+const int C_MPI_COMPARISONS[4] = {
+    MPI_IDENT,     //  0  identical
+    MPI_CONGRUENT, //  1  congruent
+    MPI_SIMILAR,   //  2  similar
+    MPI_UNEQUAL,   //  3  unequal
+};
+
+const MPI_Op C_MPI_OPS[12] = {
+    MPI_MAX,    //  0  maximum
+    MPI_MIN,    //  1  minimum
+    MPI_SUM,    //  2  sum
+    MPI_PROD,   //  3  product
+    MPI_LAND,   //  4  logical and
+    MPI_LOR,    //  5  logical or
+    MPI_LXOR,   //  6  logical xor
+    MPI_BAND,   //  7  bitwise and
+    MPI_BOR,    //  8  bitwise or
+    MPI_BXOR,   //  9  bitwise xor
+    MPI_MAXLOC, // 10  maximum and location
+    MPI_MINLOC, // 11  minimum and location
 };
 
 #endif // CONSTANTS_H
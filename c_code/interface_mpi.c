@@ -1,5 +1,6 @@
 #include <inttypes.h>
 #include <stdlib.h>
+#include <string.h>
 
 #include "mpi.h"
 
@@ -10,9 +11,19 @@
 // https://rookiehpc.org/mpi/docs/mpi_gather/index.html
 // https://mpitutorial.com/tutorials/mpi-scatter-gather-and-allgather/
 
+// key of the datatype attribute holding the context (Rust function) of a user-defined operation
+static int USER_OP_KEYVAL = MPI_KEYVAL_INVALID;
+
+static int create_user_op_keyval() {
+    return MPI_Type_create_keyval(MPI_TYPE_NULL_COPY_FN, MPI_TYPE_NULL_DELETE_FN, &USER_OP_KEYVAL, NULL); // creates a key for caching data on datatypes
+}
+
 int32_t c_mpi_init() {
     int status = MPI_Init(NULL, NULL); // initializes the MPI execution environment
-    return status;
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return create_user_op_keyval();
 }
 
 int32_t c_mpi_init_thread(int32_t option_index) {
@@ -22,7 +33,10 @@ int32_t c_mpi_init_thread(int32_t option_index) {
     if (provided != option) {
         return C_MPI_ERROR_INIT_THREADED;
     }
-    return status;
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return create_user_op_keyval();
 }
 
 int32_t c_mpi_finalize() {
@@ -45,14 +59,136 @@ int32_t c_mpi_world_size(int32_t *size) {
     return status;
 }
 
+int32_t c_mpi_buffer_attach(void *buffer, int32_t size) {
+    int status = MPI_Buffer_attach(buffer, size); // attaches a user-defined buffer for sending
+    return status;
+}
+
+int32_t c_mpi_buffer_detach() {
+    int finalized;
+    MPI_Finalized(&finalized);
+    if (finalized) {
+        return MPI_SUCCESS; // MPI_Finalize has already detached the buffer
+    }
+    void *buffer;
+    int size;
+    int status = MPI_Buffer_detach(&buffer, &size); // removes an existing buffer (waits for the buffered messages to be delivered)
+    return status;
+}
+
 struct ExtCommunicator {
     MPI_Comm handle;
     MPI_Group group;
-    MPI_Status recv_status;
 };
 
+struct ExtRequest {
+    MPI_Request handle;
+    MPI_Datatype dty;
+};
+
+struct ExtRequestSet {
+    int32_t size;
+    int32_t capacity;
+    MPI_Request *handles;
+    MPI_Datatype *types;
+};
+
+struct ExtUserOp {
+    MPI_Op handle;
+};
+
+struct ExtGroup {
+    MPI_Group handle;
+};
+
+struct ExtStatus {
+    int32_t source;
+    int32_t tag;
+    int32_t error;
+    int32_t count;
+    int32_t cancelled;
+};
+
+// derived datatypes are stored in chunks, thus the registered datatypes never move
+#define DERIVED_CHUNK_SIZE 256
+#define DERIVED_MAX_CHUNKS 256
+
+static MPI_Datatype *DERIVED_CHUNKS[DERIVED_MAX_CHUNKS];
+static int32_t DERIVED_SIZE = 0; // number of slots in use (including the freed ones)
+
+static MPI_Datatype get_datatype(int32_t type_index) {
+    if (type_index < C_MPI_N_BUILTIN_TYPES) {
+        return C_MPI_TYPES[type_index];
+    }
+    int32_t k = type_index - C_MPI_N_BUILTIN_TYPES;
+    return DERIVED_CHUNKS[k / DERIVED_CHUNK_SIZE][k % DERIVED_CHUNK_SIZE];
+}
+
+// stores the new datatype in the first free slot (frees the datatype if the registry is full)
+static int32_t register_datatype(MPI_Datatype dty, int32_t *type_index) {
+    for (int32_t k = 0; k < DERIVED_SIZE; k++) {
+        if (DERIVED_CHUNKS[k / DERIVED_CHUNK_SIZE][k % DERIVED_CHUNK_SIZE] == MPI_DATATYPE_NULL) {
+            DERIVED_CHUNKS[k / DERIVED_CHUNK_SIZE][k % DERIVED_CHUNK_SIZE] = dty;
+            *type_index = C_MPI_N_BUILTIN_TYPES + k;
+            return MPI_SUCCESS;
+        }
+    }
+    int32_t k = DERIVED_SIZE;
+    int32_t chunk = k / DERIVED_CHUNK_SIZE;
+    if (chunk >= DERIVED_MAX_CHUNKS) {
+        MPI_Type_free(&dty);
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    if (DERIVED_CHUNKS[chunk] == NULL) {
+        DERIVED_CHUNKS[chunk] = (MPI_Datatype *)malloc(DERIVED_CHUNK_SIZE * sizeof(MPI_Datatype));
+        if (DERIVED_CHUNKS[chunk] == NULL) {
+            MPI_Type_free(&dty);
+            return C_MPI_ERROR_ALLOCATION;
+        }
+    }
+    DERIVED_CHUNKS[chunk][k % DERIVED_CHUNK_SIZE] = dty;
+    DERIVED_SIZE++;
+    *type_index = C_MPI_N_BUILTIN_TYPES + k;
+    return MPI_SUCCESS;
+}
+
+static void init_status(MPI_Status *status) {
+    status->MPI_SOURCE = MPI_ANY_SOURCE;
+    status->MPI_TAG = MPI_ANY_TAG;
+    status->MPI_ERROR = MPI_SUCCESS;
+}
+
+// count < 0 means that the number of elements is undefined (or the datatype is unknown)
+static void set_ext_status(struct ExtStatus *ext, MPI_Status const *status, MPI_Datatype dty) {
+    ext->source = status->MPI_SOURCE;
+    ext->tag = status->MPI_TAG;
+    ext->error = status->MPI_ERROR;
+    ext->count = -1;
+    ext->cancelled = 0;
+    int flag;
+    if (MPI_Test_cancelled(status, &flag) == MPI_SUCCESS) {
+        ext->cancelled = flag;
+    }
+    if (dty != MPI_DATATYPE_NULL) {
+        int count;
+        if (MPI_Get_count(status, dty, &count) == MPI_SUCCESS && count != MPI_UNDEFINED) {
+            ext->count = count;
+        }
+    }
+}
+
 void comm_drop(struct ExtCommunicator *comm) {
     if (comm != NULL) {
+        int finalized;
+        MPI_Finalized(&finalized);
+        if (!finalized) { // MPI_Finalize has already freed all handles otherwise
+            if (comm->group != MPI_GROUP_NULL) {
+                MPI_Group_free(&comm->group); // marks the group for deallocation
+            }
+            if (comm->handle != MPI_COMM_NULL && comm->handle != MPI_COMM_WORLD) {
+                MPI_Comm_free(&comm->handle); // marks the communicator for deallocation
+            }
+        }
         free(comm);
     }
 }
@@ -73,36 +209,209 @@ struct ExtCommunicator *comm_new() {
     return comm;
 }
 
-struct ExtCommunicator *comm_new_subset(int32_t n_rank, int32_t const *ranks) {
+// allocates the C communicator with the given handle (returns NULL if the handle is MPI_COMM_NULL)
+static int32_t wrap_new_comm(MPI_Comm handle, struct ExtCommunicator **new_comm) {
+    *new_comm = NULL;
+    if (handle == MPI_COMM_NULL) {
+        return MPI_SUCCESS;
+    }
+
     struct ExtCommunicator *comm = (struct ExtCommunicator *)malloc(sizeof(struct ExtCommunicator));
     if (comm == NULL) {
-        return NULL;
+        MPI_Comm_free(&handle);
+        return C_MPI_ERROR_ALLOCATION;
     }
 
+    comm->handle = handle;
+    int status = MPI_Comm_group(handle, &comm->group); // returns the group associated with a communicator
+    if (status != MPI_SUCCESS) {
+        MPI_Comm_free(&comm->handle);
+        free(comm);
+        return status;
+    }
+
+    *new_comm = comm;
+    return MPI_SUCCESS;
+}
+
+// returns the group of the world communicator with only the listed members
+static int32_t world_subgroup(int32_t n_rank, int32_t const *ranks, MPI_Group *group) {
     MPI_Group world_group;
     int status = MPI_Comm_group(MPI_COMM_WORLD, &world_group); // returns the group associated with a communicator
     if (status != MPI_SUCCESS) {
-        free(comm);
-        return NULL;
+        return status;
     }
+    status = MPI_Group_incl(world_group, n_rank, ranks, group); // produces a group by reordering an existing group and taking only listed members
+    MPI_Group_free(&world_group);
+    return status;
+}
 
-    status = MPI_Group_incl(world_group, n_rank, ranks, &comm->group); // produces a group by reordering an existing group and taking only listed members
+int32_t comm_new_subset(int32_t n_rank, int32_t const *ranks, struct ExtCommunicator **new_comm) {
+    *new_comm = NULL;
+    MPI_Group group;
+    int status = world_subgroup(n_rank, ranks, &group);
     if (status != MPI_SUCCESS) {
-        free(comm);
-        return NULL;
+        return status;
+    }
+    MPI_Comm handle;
+    status = MPI_Comm_create(MPI_COMM_WORLD, group, &handle); // creates a new communicator (MPI_COMM_NULL if this process is not in the group)
+    MPI_Group_free(&group);
+    if (status != MPI_SUCCESS) {
+        return status;
     }
+    return wrap_new_comm(handle, new_comm);
+}
 
-    status = MPI_Comm_create(MPI_COMM_WORLD, comm->group, &comm->handle); // creates a new communicator
+int32_t comm_new_subset_group(int32_t n_rank, int32_t const *ranks, int32_t tag, struct ExtCommunicator **new_comm) {
+    *new_comm = NULL;
+    MPI_Group group;
+    int status = world_subgroup(n_rank, ranks, &group);
     if (status != MPI_SUCCESS) {
-        free(comm);
-        return NULL;
+        return status;
     }
+    MPI_Comm handle;
+    status = MPI_Comm_create_group(MPI_COMM_WORLD, group, tag, &handle); // creates a new communicator (only the members of the group participate)
+    MPI_Group_free(&group);
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return wrap_new_comm(handle, new_comm);
+}
 
-    comm->recv_status.MPI_SOURCE = 0;
-    comm->recv_status.MPI_TAG = 0;
-    comm->recv_status.MPI_ERROR = 0;
+int32_t comm_split(struct ExtCommunicator *comm, int32_t color, int32_t key, struct ExtCommunicator **new_comm) {
+    int c = color < 0 ? MPI_UNDEFINED : color;
+    MPI_Comm handle;
+    int status = MPI_Comm_split(comm->handle, c, key, &handle); // creates new communicators based on colors and keys
+    if (status != MPI_SUCCESS) {
+        *new_comm = NULL;
+        return status;
+    }
+    return wrap_new_comm(handle, new_comm);
+}
 
-    return comm;
+int32_t comm_split_shared(struct ExtCommunicator *comm, int32_t key, struct ExtCommunicator **new_comm) {
+    MPI_Comm handle;
+    int status = MPI_Comm_split_type(comm->handle, MPI_COMM_TYPE_SHARED, key, MPI_INFO_NULL, &handle); // creates communicators of processes that can share memory
+    if (status != MPI_SUCCESS) {
+        *new_comm = NULL;
+        return status;
+    }
+    return wrap_new_comm(handle, new_comm);
+}
+
+int32_t comm_duplicate(struct ExtCommunicator *comm, struct ExtCommunicator **new_comm) {
+    MPI_Comm handle;
+    int status = MPI_Comm_dup(comm->handle, &handle); // duplicates a communicator with all its cached information
+    if (status != MPI_SUCCESS) {
+        *new_comm = NULL;
+        return status;
+    }
+    return wrap_new_comm(handle, new_comm);
+}
+
+// the group of the new communicator is set by comm_idup_complete after the request completes
+int32_t comm_idup(struct ExtCommunicator *comm, struct ExtRequest *req, struct ExtCommunicator **new_comm) {
+    *new_comm = NULL;
+    struct ExtCommunicator *dup = (struct ExtCommunicator *)malloc(sizeof(struct ExtCommunicator));
+    if (dup == NULL) {
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    dup->handle = MPI_COMM_NULL;
+    dup->group = MPI_GROUP_NULL;
+    int status = MPI_Comm_idup(comm->handle, &dup->handle, &req->handle); // duplicates a communicator (non-blocking)
+    if (status != MPI_SUCCESS) {
+        free(dup);
+        return status;
+    }
+    *new_comm = dup;
+    return MPI_SUCCESS;
+}
+
+int32_t comm_idup_complete(struct ExtCommunicator *comm) {
+    int status = MPI_Comm_group(comm->handle, &comm->group); // returns the group associated with a communicator
+    return status;
+}
+
+int32_t comm_compare(struct ExtCommunicator *comm, struct ExtCommunicator *other, int32_t *result_index) {
+    int result;
+    int status = MPI_Comm_compare(comm->handle, other->handle, &result); // compares two communicators
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    *result_index = 3;
+    for (int32_t i = 0; i < 4; i++) {
+        if (result == C_MPI_COMPARISONS[i]) {
+            *result_index = i;
+        }
+    }
+    return status;
+}
+
+int32_t comm_set_name(struct ExtCommunicator *comm, char const *name) {
+    if (strlen(name) >= MPI_MAX_OBJECT_NAME) {
+        return C_MPI_ERROR_NAME_TOO_LONG;
+    }
+    int status = MPI_Comm_set_name(comm->handle, name); // sets the print name for a communicator
+    return status;
+}
+
+// name must have at least max_len bytes; the name is truncated to max_len bytes (not null-terminated)
+int32_t comm_get_name(struct ExtCommunicator *comm, int32_t max_len, char *name, int32_t *len) {
+    char buffer[MPI_MAX_OBJECT_NAME];
+    int n;
+    int status = MPI_Comm_get_name(comm->handle, buffer, &n); // returns the print name of a communicator
+    if (status != MPI_SUCCESS) {
+        *len = 0;
+        return status;
+    }
+    *len = n < max_len ? n : max_len;
+    memcpy(name, buffer, *len);
+    return MPI_SUCCESS;
+}
+
+// allocates the C group with the given handle (the handle is freed if the allocation fails)
+static int32_t wrap_new_group(MPI_Group handle, struct ExtGroup **new_group) {
+    struct ExtGroup *group = (struct ExtGroup *)malloc(sizeof(struct ExtGroup));
+    if (group == NULL) {
+        *new_group = NULL;
+        if (handle != MPI_GROUP_EMPTY) {
+            MPI_Group_free(&handle);
+        }
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    group->handle = handle;
+    *new_group = group;
+    return MPI_SUCCESS;
+}
+
+int32_t comm_group(struct ExtCommunicator *comm, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Comm_group(comm->handle, &handle); // returns the group associated with a communicator
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+int32_t comm_from_group(struct ExtCommunicator *comm, struct ExtGroup *group, struct ExtCommunicator **new_comm) {
+    MPI_Comm handle;
+    int status = MPI_Comm_create(comm->handle, group->handle, &handle); // creates a new communicator (MPI_COMM_NULL if this process is not in the group)
+    if (status != MPI_SUCCESS) {
+        *new_comm = NULL;
+        return status;
+    }
+    return wrap_new_comm(handle, new_comm);
+}
+
+int32_t comm_from_group_members(struct ExtCommunicator *comm, struct ExtGroup *group, int32_t tag, struct ExtCommunicator **new_comm) {
+    MPI_Comm handle;
+    int status = MPI_Comm_create_group(comm->handle, group->handle, tag, &handle); // creates a new communicator (only the members of the group participate)
+    if (status != MPI_SUCCESS) {
+        *new_comm = NULL;
+        return status;
+    }
+    return wrap_new_comm(handle, new_comm);
 }
 
 int32_t comm_abort(struct ExtCommunicator *comm, int32_t error_code) {
@@ -126,83 +435,864 @@ int32_t comm_size(struct ExtCommunicator *comm, int32_t *size) {
 }
 
 int32_t comm_broadcast(struct ExtCommunicator *comm, int32_t sender, int32_t n, void *x, int32_t type_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Bcast(x, n, dty, sender, comm->handle); // broadcasts a message from the process with rank root to all other processes of the group
     return status;
 }
 
 int32_t comm_reduce(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, void const *orig, int32_t type_index, int32_t op_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     MPI_Op op = C_MPI_OPS[op_index];
     int status = MPI_Reduce(orig, dest, n, dty, op, root, comm->handle); // reduces values on all processes within a group
     return status;
 }
 
 int32_t comm_allreduce(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index, int32_t op_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     MPI_Op op = C_MPI_OPS[op_index];
     int status = MPI_Allreduce(orig, dest, n, dty, op, comm->handle); // combines values from all processes and distributes the result back to all processes
     return status;
 }
 
+int32_t comm_reduce_in_place_im_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *data, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Reduce(MPI_IN_PLACE, data, n, dty, op, root, comm->handle); // reduces values on all processes within a group (in place)
+    return status;
+}
+
+int32_t comm_reduce_in_place_im_not_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void const *data, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Reduce(data, NULL, n, dty, op, root, comm->handle); // reduces values on all processes within a group
+    return status;
+}
+
+int32_t comm_allreduce_in_place(struct ExtCommunicator *comm, int32_t n, void *data, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Allreduce(MPI_IN_PLACE, data, n, dty, op, comm->handle); // combines values from all processes and distributes the result back to all processes (in place)
+    return status;
+}
+
+// duplicates the datatype and attaches the context of the user-defined operation to the duplicate
+// (MPI gives the datatype to the user function; thus, the context is found from any thread)
+static int user_op_datatype(int32_t type_index, void *context, MPI_Datatype *dty) {
+    int status = MPI_Type_dup(get_datatype(type_index), dty); // duplicates a datatype (including the committed state)
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    status = MPI_Type_set_attr(*dty, USER_OP_KEYVAL, context); // stores the context as an attribute of the datatype
+    if (status != MPI_SUCCESS) {
+        MPI_Type_free(dty);
+    }
+    return status;
+}
+
+// returns the context attached to the datatype of a user-defined operation (NULL if not found)
+void *user_op_context(MPI_Datatype *dty) {
+    void *context;
+    int flag;
+    if (MPI_Type_get_attr(*dty, USER_OP_KEYVAL, &context, &flag) != MPI_SUCCESS || !flag) {
+        return NULL;
+    }
+    return context;
+}
+
+int32_t comm_reduce_user_op(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, void const *orig, int32_t type_index, struct ExtUserOp *op, void *context) {
+    MPI_Datatype dty;
+    int status = user_op_datatype(type_index, context, &dty);
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    status = MPI_Reduce(orig, dest, n, dty, op->handle, root, comm->handle); // reduces values on all processes within a group
+    MPI_Type_free(&dty);
+    return status;
+}
+
+int32_t comm_allreduce_user_op(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index, struct ExtUserOp *op, void *context) {
+    MPI_Datatype dty;
+    int status = user_op_datatype(type_index, context, &dty);
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    status = MPI_Allreduce(orig, dest, n, dty, op->handle, comm->handle); // combines values from all processes and distributes the result back to all processes
+    MPI_Type_free(&dty);
+    return status;
+}
+
+int32_t comm_scan_user_op(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index, struct ExtUserOp *op, void *context) {
+    MPI_Datatype dty;
+    int status = user_op_datatype(type_index, context, &dty);
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    status = MPI_Scan(orig, dest, n, dty, op->handle, comm->handle); // computes the inclusive prefix reduction
+    MPI_Type_free(&dty);
+    return status;
+}
+
+int32_t comm_scan(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Scan(orig, dest, n, dty, op, comm->handle); // computes the inclusive prefix reduction
+    return status;
+}
+
+int32_t comm_exscan(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Exscan(orig, dest, n, dty, op, comm->handle); // computes the exclusive prefix reduction
+    return status;
+}
+
+// len(dest) must be equal to n
+// len(orig) must be equal to n * n_processors
+int32_t comm_reduce_scatter_block(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Reduce_scatter_block(orig, dest, n, dty, op, comm->handle); // reduces values and scatters equal blocks of the result
+    return status;
+}
+
+// len(counts) must be equal to n_processors
+int32_t comm_reduce_scatter(struct ExtCommunicator *comm, void *dest, void const *orig, int32_t const *counts, int32_t type_index, int32_t op_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Op op = C_MPI_OPS[op_index];
+    int status = MPI_Reduce_scatter(orig, dest, counts, dty, op, comm->handle); // reduces values and scatters varying blocks of the result
+    return status;
+}
+
 int32_t comm_send(struct ExtCommunicator *comm, int32_t n, void *data, int32_t type_index, int32_t to_rank, int32_t tag) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Send(data, n, dty, to_rank, tag, comm->handle); // performs a standard-mode blocking send
     return status;
 }
 
+int32_t comm_ssend(struct ExtCommunicator *comm, int32_t n, void const *data, int32_t type_index, int32_t to_rank, int32_t tag) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Ssend(data, n, dty, to_rank, tag, comm->handle); // performs a synchronous-mode blocking send
+    return status;
+}
+
+int32_t comm_bsend(struct ExtCommunicator *comm, int32_t n, void const *data, int32_t type_index, int32_t to_rank, int32_t tag) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Bsend(data, n, dty, to_rank, tag, comm->handle); // performs a buffered-mode blocking send
+    return status;
+}
+
+int32_t comm_rsend(struct ExtCommunicator *comm, int32_t n, void const *data, int32_t type_index, int32_t to_rank, int32_t tag) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Rsend(data, n, dty, to_rank, tag, comm->handle); // performs a ready-mode blocking send
+    return status;
+}
+
+// size is the number of bytes required by the attached buffer to hold one message
+int32_t comm_bsend_size(struct ExtCommunicator *comm, int32_t n, int32_t type_index, int32_t *size) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Pack_size(n, dty, comm->handle, size); // returns the upper bound on the amount of space needed to pack a message
+    *size += MPI_BSEND_OVERHEAD;
+    return status;
+}
+
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// tag < 0 corresponds to MPI_ANY_TAG
+int32_t comm_receive(struct ExtCommunicator *comm, int32_t n, void *data, int32_t type_index, int32_t from_rank, int32_t tag, struct ExtStatus *ext_status) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = tag < 0 ? MPI_ANY_TAG : tag;
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Recv(data, n, dty, r, t, comm->handle, &status); // performs a standard-mode blocking receive
+    set_ext_status(ext_status, &status, dty);
+    return res;
+}
+
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// recv_tag < 0 corresponds to MPI_ANY_TAG
+int32_t comm_sendrecv(struct ExtCommunicator *comm, int32_t n_send, void const *send_data, int32_t send_type_index, int32_t to_rank, int32_t send_tag, int32_t n_recv, void *recv_data, int32_t recv_type_index, int32_t from_rank, int32_t recv_tag, struct ExtStatus *ext_status) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = recv_tag < 0 ? MPI_ANY_TAG : recv_tag;
+    MPI_Datatype send_dty = get_datatype(send_type_index);
+    MPI_Datatype recv_dty = get_datatype(recv_type_index);
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Sendrecv(send_data, n_send, send_dty, to_rank, send_tag, recv_data, n_recv, recv_dty, r, t, comm->handle, &status); // sends and receives a message
+    set_ext_status(ext_status, &status, recv_dty);
+    return res;
+}
+
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// recv_tag < 0 corresponds to MPI_ANY_TAG
+int32_t comm_sendrecv_replace(struct ExtCommunicator *comm, int32_t n, void *data, int32_t type_index, int32_t to_rank, int32_t send_tag, int32_t from_rank, int32_t recv_tag, struct ExtStatus *ext_status) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = recv_tag < 0 ? MPI_ANY_TAG : recv_tag;
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Sendrecv_replace(data, n, dty, to_rank, send_tag, r, t, comm->handle, &status); // sends and receives using a single buffer
+    set_ext_status(ext_status, &status, dty);
+    return res;
+}
+
+int32_t comm_isend(struct ExtCommunicator *comm, struct ExtRequest *req, int32_t n, void const *data, int32_t type_index, int32_t to_rank, int32_t tag) {
+    MPI_Datatype dty = get_datatype(type_index);
+    req->dty = dty;
+    int status = MPI_Isend(data, n, dty, to_rank, tag, comm->handle, &req->handle); // starts a standard-mode, nonblocking send
+    return status;
+}
+
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// tag < 0 corresponds to MPI_ANY_TAG
+int32_t comm_irecv(struct ExtCommunicator *comm, struct ExtRequest *req, int32_t n, void *data, int32_t type_index, int32_t from_rank, int32_t tag) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = tag < 0 ? MPI_ANY_TAG : tag;
+    MPI_Datatype dty = get_datatype(type_index);
+    req->dty = dty;
+    int status = MPI_Irecv(data, n, dty, r, t, comm->handle, &req->handle); // starts a standard-mode, nonblocking receive
+    return status;
+}
+
+int32_t comm_send_init(struct ExtCommunicator *comm, struct ExtRequest *req, int32_t n, void const *data, int32_t type_index, int32_t to_rank, int32_t tag) {
+    MPI_Datatype dty = get_datatype(type_index);
+    req->dty = dty;
+    int status = MPI_Send_init(data, n, dty, to_rank, tag, comm->handle, &req->handle); // creates a persistent request for a standard send
+    return status;
+}
+
 // from_rank < 0 corresponds to MPI_ANY_SOURCE
 // tag < 0 corresponds to MPI_ANY_TAG
-int32_t comm_receive(struct ExtCommunicator *comm, int32_t n, void *data, int32_t type_index, int32_t from_rank, int32_t tag) {
+int32_t comm_recv_init(struct ExtCommunicator *comm, struct ExtRequest *req, int32_t n, void *data, int32_t type_index, int32_t from_rank, int32_t tag) {
     int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
     int t = tag < 0 ? MPI_ANY_TAG : tag;
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
-    comm->recv_status.MPI_SOURCE = r;
-    comm->recv_status.MPI_TAG = t;
-    comm->recv_status.MPI_ERROR = MPI_SUCCESS;
-    int status = MPI_Recv(data, n, dty, r, t, comm->handle, &comm->recv_status); // performs a standard-mode blocking receive
+    MPI_Datatype dty = get_datatype(type_index);
+    req->dty = dty;
+    int status = MPI_Recv_init(data, n, dty, r, t, comm->handle, &req->handle); // creates a persistent request for a receive
     return status;
 }
 
-void comm_get_receive_status(struct ExtCommunicator *comm, int32_t *source, int32_t *tag, int32_t *error) {
-    *source = comm->recv_status.MPI_SOURCE;
-    *tag = comm->recv_status.MPI_TAG;
-    *error = comm->recv_status.MPI_ERROR;
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// tag < 0 corresponds to MPI_ANY_TAG
+int32_t comm_probe(struct ExtCommunicator *comm, int32_t from_rank, int32_t tag, int32_t type_index, struct ExtStatus *ext_status) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = tag < 0 ? MPI_ANY_TAG : tag;
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Probe(r, t, comm->handle, &status); // blocking test for a message
+    set_ext_status(ext_status, &status, dty);
+    return res;
+}
+
+// receives and discards a matched message (a matched message must always be received)
+static int drain_message(MPI_Message *message, MPI_Status *status) {
+    int n_bytes;
+    if (MPI_Get_count(status, MPI_BYTE, &n_bytes) != MPI_SUCCESS || n_bytes == MPI_UNDEFINED) {
+        n_bytes = 0;
+    }
+    void *bytes = malloc(n_bytes > 0 ? n_bytes : 1);
+    int res = MPI_Mrecv(bytes, bytes == NULL ? 0 : n_bytes, MPI_BYTE, message, status);
+    free(bytes);
+    return res;
+}
+
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// tag < 0 corresponds to MPI_ANY_TAG
+// allocate(context, n) must return a buffer for the n elements of the matched message
+// a message whose size is not a multiple of the datatype size is received as bytes and discarded
+int32_t comm_mprobe_receive(struct ExtCommunicator *comm, int32_t from_rank, int32_t tag, int32_t type_index, void *(*allocate)(void *, int32_t), void *context, struct ExtStatus *ext_status) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = tag < 0 ? MPI_ANY_TAG : tag;
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Message message;
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Mprobe(r, t, comm->handle, &message, &status); // blocking test for a message (matched; no other receive can take it)
+    if (res != MPI_SUCCESS) {
+        set_ext_status(ext_status, &status, dty);
+        return res;
+    }
+    int n;
+    res = MPI_Get_count(&status, dty, &n);
+    if (res != MPI_SUCCESS) {
+        drain_message(&message, &status);
+        return res;
+    }
+    if (n == MPI_UNDEFINED) {
+        res = drain_message(&message, &status);
+        set_ext_status(ext_status, &status, dty);
+        return res == MPI_SUCCESS ? C_MPI_ERROR_MESSAGE_SIZE : res;
+    }
+    void *data = allocate(context, n);
+    init_status(&status);
+    res = MPI_Mrecv(data, n, dty, &message, &status); // blocking receive of the matched message
+    set_ext_status(ext_status, &status, dty);
+    return res;
+}
+
+// from_rank < 0 corresponds to MPI_ANY_SOURCE
+// tag < 0 corresponds to MPI_ANY_TAG
+int32_t comm_iprobe(struct ExtCommunicator *comm, int32_t from_rank, int32_t tag, int32_t type_index, int32_t *flag, struct ExtStatus *ext_status) {
+    int r = from_rank < 0 ? MPI_ANY_SOURCE : from_rank;
+    int t = tag < 0 ? MPI_ANY_TAG : tag;
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Iprobe(r, t, comm->handle, flag, &status); // nonblocking test for a message
+    set_ext_status(ext_status, &status, dty);
+    return res;
 }
 
 // len(dest) must be equal to n * n_processors
 // len(orig) must be equal to n
 int32_t comm_gather_im_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, void const *orig, int32_t type_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Gather(orig, n, dty, dest, n, dty, root, comm->handle); // gathers values from a group of processes.
     return status;
 }
 
 int32_t comm_gather_im_not_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void const *orig, int32_t type_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Gather(orig, n, dty, NULL, 0, dty, root, comm->handle); // gathers values from a group of processes.
     return status;
 }
 
+// len(data) must be equal to n * n_processors
+int32_t comm_gather_in_place_im_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *data, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Gather(MPI_IN_PLACE, n, dty, data, n, dty, root, comm->handle); // gathers values from a group of processes (in place)
+    return status;
+}
+
 // len(dest) must be equal to n * n_processors
 // len(orig) must be equal to n
 int32_t comm_allgather(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Allgather(orig, n, dty, dest, n, dty, comm->handle); // gathers data from all processes
     return status;
 }
 
+// len(data) must be equal to n * n_processors
+int32_t comm_allgather_in_place(struct ExtCommunicator *comm, int32_t n, void *data, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Allgather(MPI_IN_PLACE, n, dty, data, n, dty, comm->handle); // gathers data from all processes (in place)
+    return status;
+}
+
 // len(dest) must be equal to n
 // len(orig) must be equal to n * n_processors
 int32_t comm_scatter_im_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, void const *orig, int32_t type_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Scatter(orig, n, dty, dest, n, dty, root, comm->handle); // sends data from one task to all tasks in a group
     return status;
 }
 
 // len(dest) must be equal to n
 int32_t comm_scatter_im_not_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, int32_t type_index) {
-    MPI_Datatype dty = C_MPI_TYPES[type_index];
+    MPI_Datatype dty = get_datatype(type_index);
     int status = MPI_Scatter(NULL, 0, dty, dest, n, dty, root, comm->handle); // sends data from one task to all tasks in a group
     return status;
 }
+
+// len(counts) and len(displs) must be equal to n_processors
+int32_t comm_gatherv_im_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, int32_t const *counts, int32_t const *displs, void const *orig, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Gatherv(orig, n, dty, dest, counts, displs, dty, root, comm->handle); // gathers varying amounts of data from all processes
+    return status;
+}
+
+int32_t comm_gatherv_im_not_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void const *orig, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Gatherv(orig, n, dty, NULL, NULL, NULL, dty, root, comm->handle); // gathers varying amounts of data from all processes
+    return status;
+}
+
+// len(counts) and len(displs) must be equal to n_processors
+int32_t comm_allgatherv(struct ExtCommunicator *comm, int32_t n, void *dest, int32_t const *counts, int32_t const *displs, void const *orig, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Allgatherv(orig, n, dty, dest, counts, displs, dty, comm->handle); // gathers varying amounts of data from all processes to all processes
+    return status;
+}
+
+// len(counts) and len(displs) must be equal to n_processors
+int32_t comm_scatterv_im_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, int32_t const *counts, int32_t const *displs, void const *orig, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Scatterv(orig, counts, displs, dty, dest, n, dty, root, comm->handle); // scatters varying amounts of data from one task to all tasks
+    return status;
+}
+
+int32_t comm_scatterv_im_not_root(struct ExtCommunicator *comm, int32_t root, int32_t n, void *dest, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Scatterv(NULL, NULL, NULL, dty, dest, n, dty, root, comm->handle); // scatters varying amounts of data from one task to all tasks
+    return status;
+}
+
+// len(dest) must be equal to n * n_processors
+// len(orig) must be equal to n * n_processors
+int32_t comm_alltoall(struct ExtCommunicator *comm, int32_t n, void *dest, void const *orig, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Alltoall(orig, n, dty, dest, n, dty, comm->handle); // sends data from all to all processes
+    return status;
+}
+
+// len(recv_counts), len(recv_displs), len(send_counts), and len(send_displs) must be equal to n_processors
+int32_t comm_alltoallv(struct ExtCommunicator *comm, void *dest, int32_t const *recv_counts, int32_t const *recv_displs, void const *orig, int32_t const *send_counts, int32_t const *send_displs, int32_t type_index) {
+    MPI_Datatype dty = get_datatype(type_index);
+    int status = MPI_Alltoallv(orig, send_counts, send_displs, dty, dest, recv_counts, recv_displs, dty, comm->handle); // sends varying amounts of data from all to all processes
+    return status;
+}
+
+void req_drop(struct ExtRequest *req) {
+    if (req != NULL) {
+        if (req->handle != MPI_REQUEST_NULL) {
+            MPI_Wait(&req->handle, MPI_STATUS_IGNORE); // waits for the pending operation before releasing the memory
+        }
+        free(req);
+    }
+}
+
+struct ExtRequest *req_new() {
+    struct ExtRequest *req = (struct ExtRequest *)malloc(sizeof(struct ExtRequest));
+    if (req == NULL) {
+        return NULL;
+    }
+    req->handle = MPI_REQUEST_NULL;
+    req->dty = MPI_DATATYPE_NULL;
+    return req;
+}
+
+int32_t req_wait(struct ExtRequest *req, struct ExtStatus *ext_status) {
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Wait(&req->handle, &status); // waits for an MPI request to complete
+    set_ext_status(ext_status, &status, req->dty);
+    return res;
+}
+
+int32_t req_test(struct ExtRequest *req, int32_t *flag, struct ExtStatus *ext_status) {
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Test(&req->handle, flag, &status); // tests for the completion of a request
+    set_ext_status(ext_status, &status, req->dty);
+    return res;
+}
+
+void preq_drop(struct ExtRequest *req) {
+    if (req != NULL) {
+        if (req->handle != MPI_REQUEST_NULL) {
+            MPI_Wait(&req->handle, MPI_STATUS_IGNORE); // waits for the active operation (if any); the request remains allocated
+            MPI_Request_free(&req->handle);            // frees the persistent request
+        }
+        free(req);
+    }
+}
+
+int32_t preq_start(struct ExtRequest *req) {
+    int status = MPI_Start(&req->handle); // starts a communication with a persistent request handle
+    return status;
+}
+
+int32_t preq_start_all(int32_t n, struct ExtRequest **reqs) {
+    if (n == 0) {
+        return MPI_SUCCESS;
+    }
+    MPI_Request *handles = (MPI_Request *)malloc(n * sizeof(MPI_Request));
+    if (handles == NULL) {
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    for (int32_t i = 0; i < n; i++) {
+        handles[i] = reqs[i]->handle;
+    }
+    int status = MPI_Startall(n, handles); // starts a collection of persistent requests
+    for (int32_t i = 0; i < n; i++) {
+        reqs[i]->handle = handles[i];
+    }
+    free(handles);
+    return status;
+}
+
+void reqset_drop(struct ExtRequestSet *set) {
+    if (set != NULL) {
+        if (set->handles != NULL) {
+            MPI_Waitall(set->size, set->handles, MPI_STATUSES_IGNORE); // waits for the pending operations before releasing the memory
+            free(set->handles);
+        }
+        if (set->types != NULL) {
+            free(set->types);
+        }
+        free(set);
+    }
+}
+
+struct ExtRequestSet *reqset_new() {
+    struct ExtRequestSet *set = (struct ExtRequestSet *)malloc(sizeof(struct ExtRequestSet));
+    if (set == NULL) {
+        return NULL;
+    }
+    set->size = 0;
+    set->capacity = 0;
+    set->handles = NULL;
+    set->types = NULL;
+    return set;
+}
+
+// moves the MPI_Request from req into the set (req becomes inactive)
+int32_t reqset_push(struct ExtRequestSet *set, struct ExtRequest *req) {
+    if (set->size == set->capacity) {
+        int32_t capacity = set->capacity == 0 ? 8 : 2 * set->capacity;
+        MPI_Request *handles = (MPI_Request *)realloc(set->handles, capacity * sizeof(MPI_Request));
+        if (handles == NULL) {
+            return C_MPI_ERROR_ALLOCATION;
+        }
+        set->handles = handles;
+        MPI_Datatype *types = (MPI_Datatype *)realloc(set->types, capacity * sizeof(MPI_Datatype));
+        if (types == NULL) {
+            return C_MPI_ERROR_ALLOCATION;
+        }
+        set->types = types;
+        set->capacity = capacity;
+    }
+    set->handles[set->size] = req->handle;
+    set->types[set->size] = req->dty;
+    set->size += 1;
+    req->handle = MPI_REQUEST_NULL;
+    return MPI_SUCCESS;
+}
+
+// len(ext_statuses) must be equal to set->size
+int32_t reqset_wait_all(struct ExtRequestSet *set, struct ExtStatus *ext_statuses) {
+    if (set->size == 0) {
+        return MPI_SUCCESS;
+    }
+    MPI_Status *statuses = (MPI_Status *)malloc(set->size * sizeof(MPI_Status));
+    if (statuses == NULL) {
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    for (int32_t i = 0; i < set->size; i++) {
+        init_status(&statuses[i]);
+    }
+    int res = MPI_Waitall(set->size, set->handles, statuses); // waits for all given MPI requests to complete
+    for (int32_t i = 0; i < set->size; i++) {
+        set_ext_status(&ext_statuses[i], &statuses[i], set->types[i]);
+    }
+    free(statuses);
+    return res;
+}
+
+// index < 0 means that there are no active requests
+int32_t reqset_wait_any(struct ExtRequestSet *set, int32_t *index, struct ExtStatus *ext_status) {
+    if (set->size == 0) {
+        *index = -1;
+        return MPI_SUCCESS;
+    }
+    MPI_Status status;
+    init_status(&status);
+    int res = MPI_Waitany(set->size, set->handles, index, &status); // waits for any specified MPI request to complete
+    if (*index == MPI_UNDEFINED) {
+        *index = -1;
+        set_ext_status(ext_status, &status, MPI_DATATYPE_NULL);
+    } else {
+        set_ext_status(ext_status, &status, set->types[*index]);
+    }
+    return res;
+}
+
+// n_completed < 0 means that there are no active requests
+// len(indices) and len(ext_statuses) must be equal to set->size
+int32_t reqset_wait_some(struct ExtRequestSet *set, int32_t *n_completed, int32_t *indices, struct ExtStatus *ext_statuses) {
+    if (set->size == 0) {
+        *n_completed = -1;
+        return MPI_SUCCESS;
+    }
+    MPI_Status *statuses = (MPI_Status *)malloc(set->size * sizeof(MPI_Status));
+    if (statuses == NULL) {
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    for (int32_t i = 0; i < set->size; i++) {
+        init_status(&statuses[i]);
+    }
+    int res = MPI_Waitsome(set->size, set->handles, n_completed, indices, statuses); // waits for some given MPI requests to complete
+    if (*n_completed == MPI_UNDEFINED) {
+        *n_completed = -1;
+    }
+    for (int32_t i = 0; i < *n_completed; i++) {
+        set_ext_status(&ext_statuses[i], &statuses[i], set->types[indices[i]]);
+    }
+    free(statuses);
+    return res;
+}
+
+// len(ext_statuses) must be equal to set->size
+int32_t reqset_test_all(struct ExtRequestSet *set, int32_t *flag, struct ExtStatus *ext_statuses) {
+    if (set->size == 0) {
+        *flag = 1;
+        return MPI_SUCCESS;
+    }
+    MPI_Status *statuses = (MPI_Status *)malloc(set->size * sizeof(MPI_Status));
+    if (statuses == NULL) {
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    for (int32_t i = 0; i < set->size; i++) {
+        init_status(&statuses[i]);
+    }
+    int res = MPI_Testall(set->size, set->handles, flag, statuses); // tests for the completion of all previously initiated requests
+    if (*flag) {
+        for (int32_t i = 0; i < set->size; i++) {
+            set_ext_status(&ext_statuses[i], &statuses[i], set->types[i]);
+        }
+    }
+    free(statuses);
+    return res;
+}
+
+void user_op_drop(struct ExtUserOp *op) {
+    if (op != NULL) {
+        int finalized;
+        MPI_Finalized(&finalized);
+        if (!finalized && op->handle != MPI_OP_NULL) {
+            MPI_Op_free(&op->handle); // frees the user-defined reduction operation
+        }
+        free(op);
+    }
+}
+
+struct ExtUserOp *user_op_new(MPI_User_function *function, int32_t commute) {
+    struct ExtUserOp *op = (struct ExtUserOp *)malloc(sizeof(struct ExtUserOp));
+    if (op == NULL) {
+        return NULL;
+    }
+    int status = MPI_Op_create(function, commute, &op->handle); // creates a user-defined reduction operation
+    if (status != MPI_SUCCESS) {
+        free(op);
+        return NULL;
+    }
+    return op;
+}
+
+int32_t datatype_contiguous(int32_t count, int32_t old_type_index, int32_t *type_index) {
+    MPI_Datatype dty;
+    int status = MPI_Type_contiguous(count, get_datatype(old_type_index), &dty); // replicates a datatype into contiguous locations
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_vector(int32_t count, int32_t block_length, int32_t stride, int32_t old_type_index, int32_t *type_index) {
+    MPI_Datatype dty;
+    int status = MPI_Type_vector(count, block_length, stride, get_datatype(old_type_index), &dty); // replicates a datatype into equally spaced blocks
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_hvector(int32_t count, int32_t block_length, int64_t stride, int32_t old_type_index, int32_t *type_index) {
+    MPI_Datatype dty;
+    int status = MPI_Type_create_hvector(count, block_length, (MPI_Aint)stride, get_datatype(old_type_index), &dty); // replicates a datatype into blocks spaced by bytes
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_indexed(int32_t count, int32_t const *block_lengths, int32_t const *displacements, int32_t old_type_index, int32_t *type_index) {
+    MPI_Datatype dty;
+    int status = MPI_Type_indexed(count, block_lengths, displacements, get_datatype(old_type_index), &dty); // replicates a datatype into a sequence of blocks
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_subarray(int32_t ndims, int32_t const *sizes, int32_t const *subsizes, int32_t const *starts, int32_t order_index, int32_t old_type_index, int32_t *type_index) {
+    MPI_Datatype dty;
+    int order = C_MPI_ORDERS[order_index];
+    int status = MPI_Type_create_subarray(ndims, sizes, subsizes, starts, order, get_datatype(old_type_index), &dty); // describes a subarray of a multidimensional array
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_struct(int32_t count, int32_t const *block_lengths, int64_t const *displacements, int32_t const *type_indices, int32_t *type_index) {
+    MPI_Aint *displs = (MPI_Aint *)malloc(count * sizeof(MPI_Aint));
+    MPI_Datatype *types = (MPI_Datatype *)malloc(count * sizeof(MPI_Datatype));
+    if (displs == NULL || types == NULL) {
+        free(displs);
+        free(types);
+        return C_MPI_ERROR_ALLOCATION;
+    }
+    for (int32_t i = 0; i < count; i++) {
+        displs[i] = (MPI_Aint)displacements[i];
+        types[i] = get_datatype(type_indices[i]);
+    }
+    MPI_Datatype dty;
+    int status = MPI_Type_create_struct(count, block_lengths, displs, types, &dty); // describes a structure with fields of (possibly) different datatypes
+    free(displs);
+    free(types);
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_resized(int32_t old_type_index, int64_t lb, int64_t extent, int32_t *type_index) {
+    MPI_Datatype dty;
+    int status = MPI_Type_create_resized(get_datatype(old_type_index), (MPI_Aint)lb, (MPI_Aint)extent, &dty); // changes the lower bound and extent of a datatype
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    return register_datatype(dty, type_index);
+}
+
+int32_t datatype_commit(int32_t type_index) {
+    int32_t k = type_index - C_MPI_N_BUILTIN_TYPES;
+    int status = MPI_Type_commit(&DERIVED_CHUNKS[k / DERIVED_CHUNK_SIZE][k % DERIVED_CHUNK_SIZE]); // commits the datatype (required before communication)
+    return status;
+}
+
+void datatype_free(int32_t type_index) {
+    int32_t k = type_index - C_MPI_N_BUILTIN_TYPES;
+    MPI_Datatype *dty = &DERIVED_CHUNKS[k / DERIVED_CHUNK_SIZE][k % DERIVED_CHUNK_SIZE];
+    int finalized;
+    MPI_Finalized(&finalized);
+    if (!finalized && *dty != MPI_DATATYPE_NULL) {
+        MPI_Type_free(dty); // marks the datatype for deallocation (sets it to MPI_DATATYPE_NULL)
+    }
+    *dty = MPI_DATATYPE_NULL; // releases the slot
+}
+
+int32_t datatype_size(int32_t type_index, int64_t *size) {
+    int n;
+    int status = MPI_Type_size(get_datatype(type_index), &n); // returns the number of bytes of data in the datatype
+    *size = n;
+    return status;
+}
+
+int32_t datatype_extent(int32_t type_index, int64_t *lb, int64_t *extent, int64_t *true_lb, int64_t *true_extent) {
+    MPI_Datatype dty = get_datatype(type_index);
+    MPI_Aint a, b, c, d;
+    int status = MPI_Type_get_extent(dty, &a, &b); // returns the lower bound and extent of the datatype
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    status = MPI_Type_get_true_extent(dty, &c, &d); // returns the true lower bound and extent of the datatype (ignoring resizing)
+    *lb = a;
+    *extent = b;
+    *true_lb = c;
+    *true_extent = d;
+    return status;
+}
+
+void group_drop(struct ExtGroup *group) {
+    if (group != NULL) {
+        int finalized;
+        MPI_Finalized(&finalized);
+        if (!finalized && group->handle != MPI_GROUP_NULL && group->handle != MPI_GROUP_EMPTY) {
+            MPI_Group_free(&group->handle); // marks the group for deallocation
+        }
+        free(group);
+    }
+}
+
+int32_t group_size(struct ExtGroup *group, int32_t *size) {
+    int status = MPI_Group_size(group->handle, size); // returns the size of a group
+    return status;
+}
+
+// rank = -1 if the calling process is not a member of the group
+int32_t group_rank(struct ExtGroup *group, int32_t *rank) {
+    int status = MPI_Group_rank(group->handle, rank); // returns the rank of the calling process in the group
+    if (*rank == MPI_UNDEFINED) {
+        *rank = -1;
+    }
+    return status;
+}
+
+int32_t group_union(struct ExtGroup *group, struct ExtGroup *other, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Group_union(group->handle, other->handle, &handle); // produces a group by combining two groups
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+int32_t group_intersection(struct ExtGroup *group, struct ExtGroup *other, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Group_intersection(group->handle, other->handle, &handle); // produces a group as the intersection of two existing groups
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+int32_t group_difference(struct ExtGroup *group, struct ExtGroup *other, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Group_difference(group->handle, other->handle, &handle); // makes a group from the difference of two groups
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+int32_t group_include(struct ExtGroup *group, int32_t n, int32_t const *ranks, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Group_incl(group->handle, n, ranks, &handle); // produces a group by reordering an existing group and taking only listed members
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+int32_t group_exclude(struct ExtGroup *group, int32_t n, int32_t const *ranks, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Group_excl(group->handle, n, ranks, &handle); // produces a group by reordering an existing group and taking only unlisted members
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+// len(ranges) = 3 * n with (first, last, stride) triplets
+int32_t group_range_include(struct ExtGroup *group, int32_t n, int32_t const *ranges, struct ExtGroup **new_group) {
+    MPI_Group handle;
+    int status = MPI_Group_range_incl(group->handle, n, (int(*)[3])ranges, &handle); // creates a new group from ranges of ranks in an existing group
+    if (status != MPI_SUCCESS) {
+        *new_group = NULL;
+        return status;
+    }
+    return wrap_new_group(handle, new_group);
+}
+
+// other_ranks[i] = -1 if the process is not a member of the other group
+int32_t group_translate_ranks(struct ExtGroup *group, int32_t n, int32_t const *ranks, struct ExtGroup *other, int32_t *other_ranks) {
+    int status = MPI_Group_translate_ranks(group->handle, n, ranks, other->handle, other_ranks); // translates the ranks of processes in one group to those in another group
+    for (int32_t i = 0; i < n; i++) {
+        if (other_ranks[i] == MPI_UNDEFINED) {
+            other_ranks[i] = -1;
+        }
+    }
+    return status;
+}
+
+int32_t group_compare(struct ExtGroup *group, struct ExtGroup *other, int32_t *result_index) {
+    int result;
+    int status = MPI_Group_compare(group->handle, other->handle, &result); // compares two groups
+    if (status != MPI_SUCCESS) {
+        return status;
+    }
+    *result_index = 3;
+    for (int32_t i = 0; i < 4; i++) {
+        if (result == C_MPI_COMPARISONS[i]) {
+            *result_index = i;
+        }
+    }
+    return status;
+}
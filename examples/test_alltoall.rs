@@ -0,0 +1,61 @@
+use msgpass::*;
+use num_complex::Complex64;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 2;
+
+    // alltoall: process r sends N values equal to 10*r+i to process i
+    let orig: Vec<i32> = (0..size).flat_map(|i| vec![(10 * rank + i) as i32; N]).collect();
+    let mut dest = vec![0_i32; N * size];
+    comm.alltoall(&mut dest, &orig)?;
+    let correct: Vec<i32> = (0..size).flat_map(|j| vec![(10 * j + rank) as i32; N]).collect();
+    assert_eq!(dest, correct);
+
+    // alltoall with complex numbers (a distributed transpose)
+    let orig: Vec<Complex64> = (0..size).map(|i| Complex64::new(rank as f64, i as f64)).collect();
+    let mut dest = vec![Complex64::new(0.0, 0.0); size];
+    comm.alltoall(&mut dest, &orig)?;
+    let correct: Vec<Complex64> = (0..size).map(|j| Complex64::new(j as f64, rank as f64)).collect();
+    assert_eq!(dest, correct);
+
+    // length validation
+    let mut wrong = vec![0_i32; N * size + 1];
+    let orig = vec![0_i32; N * size];
+    assert_eq!(comm.alltoall(&mut wrong, &orig).err(), Some("dest.len() must equal orig.len()"));
+
+    // alltoallv: process r sends i+1 values equal to r to process i
+    let send_counts: Vec<usize> = (0..size).map(|i| i + 1).collect();
+    let send_displs: Vec<usize> = (0..size).map(|i| i * (i + 1) / 2).collect();
+    let orig: Vec<f64> = (0..size).flat_map(|i| vec![rank as f64; i + 1]).collect();
+    let recv_counts = vec![rank + 1; size];
+    let recv_displs: Vec<usize> = (0..size).map(|j| j * (rank + 1)).collect();
+    let mut dest = vec![0.0; size * (rank + 1)];
+    comm.alltoallv(&mut dest, &recv_counts, &recv_displs, &orig, &send_counts, &send_displs)?;
+    let correct: Vec<f64> = (0..size).flat_map(|j| vec![j as f64; rank + 1]).collect();
+    assert_eq!(dest, correct);
+
+    // alltoall with automatic counts
+    let (data, offsets) = comm.alltoall_flat(&orig, &send_counts)?;
+    assert_eq!(offsets.len(), size + 1);
+    assert_eq!(data, correct);
+
+    let orig: Vec<Vec<u64>> = (0..size).map(|i| vec![(rank * i) as u64; rank + i]).collect();
+    let parts = comm.alltoall_vec(&orig)?;
+    assert_eq!(parts.len(), size);
+    for (j, part) in parts.iter().enumerate() {
+        assert_eq!(part, &vec![(j * rank) as u64; j + rank]);
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -17,21 +17,40 @@ fn main() -> Result<(), StrError> {
     assert_eq!(size, np);
 
     if np == 2 {
-        let mut sub = Communicator::new_subset(&[0])?;
+        let sub = Communicator::new_subset(&[0])?;
         if rank == 0 {
+            let mut sub = sub.unwrap();
             assert_eq!(sub.rank()?, 0);
             assert_eq!(sub.size()?, 1);
+        } else {
+            assert!(sub.is_none());
         }
     } else {
-        let mut sub = Communicator::new_subset(&[1, 3])?;
+        let sub = Communicator::new_subset(&[1, 3])?;
         if rank == 1 || rank == 3 {
+            let mut sub = sub.unwrap();
             let sub_rank = sub.rank()?;
             let sub_size = sub.size()?;
             assert!(sub_rank == 0 || sub_rank == 1);
             assert_eq!(sub_size, 2);
+        } else {
+            assert!(sub.is_none());
         }
     }
 
+    // only the members create the communicator
+    let members: Vec<_> = (0..np).filter(|r| r % 2 == 1).collect();
+    if members.contains(&rank) {
+        let mut sub = Communicator::new_subset_group(&members, 0)?;
+        assert_eq!(sub.rank()?, rank / 2);
+        assert_eq!(sub.size()?, members.len());
+        let mut sum = [0];
+        sub.allreduce(&mut sum, &[rank], MpiOpInt::Sum)?;
+        assert_eq!(sum[0], members.iter().sum::<usize>());
+    } else {
+        assert_eq!(Communicator::new_subset_group(&members, 0).err(), Some("this process is not a member of the subset"));
+    }
+
     mpi_finalize()?;
 
     if rank == 0 {
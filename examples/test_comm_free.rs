@@ -0,0 +1,38 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // many more communicators than the MPI implementations can hold at the same time
+    // (the test would fail if the communicators were not freed on drop)
+    const N: usize = 10_000;
+    let all: Vec<_> = (0..size).collect();
+    for i in 0..N {
+        let mut sub = Communicator::new_subset(&all)?.unwrap();
+        assert_eq!(sub.size()?, size);
+        let mut half = comm.split(Some(rank % 2), i)?.unwrap();
+        assert_eq!(half.rank()?, rank / 2);
+    }
+
+    // subset communicator with only the root process
+    for _ in 0..N {
+        let sub = Communicator::new_subset(&[0])?;
+        assert_eq!(sub.is_some(), rank == 0);
+    }
+
+    // dropping after finalize is allowed
+    let mut late = comm.split(Some(0), rank)?.unwrap();
+    assert_eq!(late.size()?, size);
+
+    mpi_finalize()?;
+    drop(late);
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use msgpass::*;
+use std::mem::{offset_of, size_of};
+use std::sync::OnceLock;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Particle {
+    id: i32,
+    position: [f64; 3],
+    active: u8,
+}
+
+// Returns the datatype of Particle (resized to the size of the Rust struct, including the padding)
+fn particle_datatype() -> Result<MpiDatatype, StrError> {
+    let fields = MpiDatatype::structure(
+        &[1, 3, 1],
+        &[offset_of!(Particle, id), offset_of!(Particle, position), offset_of!(Particle, active)],
+        &[&MpiDatatype::of::<i32>(), &MpiDatatype::of::<f64>(), &MpiDatatype::of::<u8>()],
+    )?;
+    fields.resized(0, size_of::<Particle>())?.commit()
+}
+
+static PARTICLE: OnceLock<MpiDatatype> = OnceLock::new();
+
+// Particle can be used with all generic functions (e.g., send and receive)
+unsafe impl MpiData for Particle {
+    fn type_index() -> i32 {
+        PARTICLE.get_or_init(|| particle_datatype().unwrap()).type_index()
+    }
+}
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // row-major matrix with M rows and N columns
+    const M: usize = 3;
+    const N: usize = 4;
+    let f64_type = MpiDatatype::of::<f64>();
+    let mut a = vec![0.0; M * N];
+    if rank == 0 {
+        for i in 0..M {
+            for j in 0..N {
+                a[i * N + j] = (10 * i + j) as f64;
+            }
+        }
+    }
+
+    // contiguous (broadcast the whole matrix as a single item)
+    let matrix = MpiDatatype::contiguous(M * N, &f64_type)?.commit()?;
+    assert_eq!(matrix.size()?, M * N * 8);
+    comm.broadcast_datatype(0, &mut a, 1, &matrix)?;
+    assert_eq!(a[N + 2], 12.0);
+
+    // vector (a column)
+    let column = MpiDatatype::vector(M, 1, N, &f64_type)?.commit()?;
+    assert_eq!(column.size()?, M * 8);
+    assert_eq!(column.extent()?, (0, ((M - 1) * N + 1) * 8));
+
+    // the buffer must hold all items
+    let mut small = vec![0.0; M * N - 1];
+    assert_eq!(comm.broadcast_datatype(0, &mut small, 1, &matrix).err(), Some("the buffer is too small for the datatype and count"));
+    assert_eq!(comm.broadcast_datatype(0, &mut a[2..], 1, &column).err(), Some("the buffer is too small for the datatype and count"));
+
+    // the datatype must be committed
+    let uncommitted = MpiDatatype::contiguous(2, &f64_type)?;
+    assert!(!uncommitted.is_committed());
+    assert_eq!(comm.broadcast_datatype(0, &mut a, 1, &uncommitted).err(), Some("the datatype must be committed"));
+
+    // column resized to one item, thus consecutive items are consecutive columns
+    let columns = column.resized(0, 8)?.commit()?;
+    assert_eq!(columns.extent()?, (0, 8));
+
+    // subarray (the 2x2 block at the bottom-right corner)
+    let block = MpiDatatype::subarray(&[M, N], &[2, 2], &[M - 2, N - 2], MpiOrder::RowMajor, &f64_type)?.commit()?;
+    assert_eq!(block.size()?, 4 * 8);
+
+    // indexed (the upper triangle)
+    let lengths: Vec<usize> = (0..M).map(|i| N - i).collect();
+    let displacements: Vec<usize> = (0..M).map(|i| i * N + i).collect();
+    let upper = MpiDatatype::indexed(&lengths, &displacements, &f64_type)?.commit()?;
+
+    // struct
+    let particle = particle_datatype()?;
+    assert_eq!(particle.size()?, 4 + 3 * 8 + 1);
+    assert_eq!(particle.extent()?, (0, size_of::<Particle>()));
+
+    // the original datatype may be dropped after the new one is created
+    let i32_type = MpiDatatype::of::<i32>();
+    let pair = MpiDatatype::contiguous(2, &i32_type)?;
+    let pairs = pair.resized(0, 16)?.commit()?;
+    drop(pair);
+    assert_eq!(pairs.size()?, 8);
+
+    // hvector (the first entry of each particle, i.e., the ids)
+    let ids = MpiDatatype::hvector(2, 1, size_of::<Particle>() as isize, &i32_type)?.commit()?;
+
+    if size > 1 {
+        if rank == 0 {
+            comm.send_datatype(&a[1..], 1, &column, 1, 10)?;
+            comm.send_datatype(&a, N, &columns, 1, 11)?;
+            comm.send_datatype(&a, 1, &block, 1, 12)?;
+            comm.send_datatype(&a, 1, &upper, 1, 13)?;
+            let particles = [Particle { id: 1, position: [1.0, 2.0, 3.0], active: 1 }, Particle { id: 2, position: [4.0, 5.0, 6.0], active: 0 }];
+            comm.send_datatype(&particles, 2, &particle, 1, 14)?;
+            comm.send_datatype(&particles, 1, &ids, 1, 15)?;
+            comm.send(&particles, 1, 16)?;
+        } else if rank == 1 {
+            // receive the second column into a contiguous array
+            let mut col = vec![0.0; M];
+            let status = comm.receive(&mut col, 0, 10)?;
+            assert_eq!(status.count, Some(M));
+            assert_eq!(col, &[1.0, 11.0, 21.0]);
+
+            // receive all columns into a column-major matrix (transpose)
+            let mut at = vec![0.0; M * N];
+            comm.receive(&mut at, 0, 11)?;
+            assert_eq!(at, &[0.0, 10.0, 20.0, 1.0, 11.0, 21.0, 2.0, 12.0, 22.0, 3.0, 13.0, 23.0]);
+
+            // receive the block into the same position
+            let mut b = vec![0.0; M * N];
+            let status = comm.receive_datatype(&mut b, 1, &block, 0, 12)?;
+            assert_eq!(status.count, Some(1));
+            assert_eq!(&b[N + 2..N + 4], &[12.0, 13.0]);
+            assert_eq!(&b[2 * N + 2..2 * N + 4], &[22.0, 23.0]);
+            assert_eq!(b.iter().filter(|&&x| x != 0.0).count(), 4);
+
+            // receive the upper triangle into a packed array
+            let mut u = vec![0.0; 9];
+            comm.receive(&mut u, 0, 13)?;
+            assert_eq!(u, &[0.0, 1.0, 2.0, 3.0, 11.0, 12.0, 13.0, 22.0, 23.0]);
+
+            // receive particles
+            let mut particles = [Particle { id: 0, position: [0.0; 3], active: 0 }; 2];
+            let status = comm.receive_datatype(&mut particles, 2, &particle, 0, 14)?;
+            assert_eq!(status.count, Some(2));
+            assert_eq!(particles[0], Particle { id: 1, position: [1.0, 2.0, 3.0], active: 1 });
+            assert_eq!(particles[1], Particle { id: 2, position: [4.0, 5.0, 6.0], active: 0 });
+
+            // receive the ids
+            let mut id_values = [0_i32; 2];
+            comm.receive(&mut id_values, 0, 15)?;
+            assert_eq!(id_values, [1, 2]);
+
+            // receive particles using the MpiData implementation
+            let mut particles = [Particle { id: 0, position: [0.0; 3], active: 0 }; 2];
+            let status = comm.receive(&mut particles, 0, 16)?;
+            assert_eq!(status.count, Some(2));
+            assert_eq!(particles[1].position, [4.0, 5.0, 6.0]);
+        }
+    }
+
+    // ring exchange: send to the next rank and receive from the previous rank
+    let next = (rank + 1) % size;
+    let prev = (rank + size - 1) % size;
+    let mine: Vec<f64> = (0..M * N).map(|k| (100 * rank + k) as f64).collect();
+    let theirs: Vec<f64> = (0..M * N).map(|k| (100 * prev + k) as f64).collect();
+
+    // send and receive the second column
+    let mut b = [0.0; M * N];
+    let status = comm.sendrecv_datatype(&mine[1..], next, 20, &mut b[1..], prev as i32, 20, 1, &column)?;
+    assert_eq!(status.count, Some(1));
+    for i in 0..M {
+        assert_eq!(b[i * N + 1], theirs[i * N + 1]);
+    }
+
+    // non-blocking send and receive of all columns
+    let mut c = vec![0.0; M * N];
+    let recv = unsafe { comm.irecv_datatype(&mut c, N, &columns, prev as i32, 21)? };
+    let send = unsafe { comm.isend_datatype(&mine, N, &columns, next, 21)? };
+    send.wait()?;
+    assert_eq!(recv.wait()?.count, Some(N));
+    assert_eq!(c, theirs);
+
+    // persistent send and receive of the block
+    let mut m = mine.clone();
+    let mut d = vec![0.0; M * N];
+    let mut send = unsafe { comm.send_init_datatype(&mut m, 1, &block, next, 22)? };
+    let mut recv = unsafe { comm.recv_init_datatype(&mut d, 1, &block, prev as i32, 22)? };
+    start_all(&mut [&mut recv, &mut send])?;
+    send.wait()?;
+    recv.wait()?;
+    let d = recv.data()?;
+    for k in [N + 2, N + 3, 2 * N + 2, 2 * N + 3] {
+        assert_eq!(d[k], theirs[k]);
+    }
+    assert_eq!(d.iter().filter(|&&x| x != 0.0).count(), 4);
+    drop(send);
+    drop(recv);
+
+    // gather, allgather, and scatter particles
+    let zero = Particle { id: 0, position: [0.0; 3], active: 0 };
+    let me = Particle { id: rank as i32, position: [rank as f64; 3], active: 1 };
+    let correct: Vec<_> = (0..size).map(|r| Particle { id: r as i32, position: [r as f64; 3], active: 1 }).collect();
+    let mut all = vec![zero; size];
+    comm.allgather_datatype(&mut all, &[me], 1, &particle)?;
+    assert_eq!(all, correct);
+    let mut one = [zero];
+    if rank == 0 {
+        let mut all = vec![zero; size];
+        assert_eq!(comm.gather_datatype(0, Some(&mut all[1..]), &[me], 1, &particle).err(), Some("the buffer is too small for the datatype and count"));
+        comm.gather_datatype(0, Some(&mut all), &[me], 1, &particle)?;
+        assert_eq!(all, correct);
+        comm.scatter_datatype(0, &mut one, Some(&correct), 1, &particle)?;
+    } else {
+        comm.gather_datatype(0, None, &[me], 1, &particle)?;
+        comm.scatter_datatype(0, &mut one, None, 1, &particle)?;
+    }
+    assert_eq!(one, [me]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use msgpass::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Equivalence)]
+struct MaterialPoint {
+    id: i32,
+    position: [f64; 3],
+    stress: [[f64; 3]; 3],
+    active: u8,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Equivalence)]
+struct Cell(u8, MaterialPoint);
+
+impl MaterialPoint {
+    fn new(id: usize) -> Self {
+        let x = id as f64;
+        MaterialPoint {
+            id: id as i32,
+            position: [x, 2.0 * x, 3.0 * x],
+            stress: [[x, 0.0, 0.0], [0.0, x, 0.0], [0.0, 0.0, x]],
+            active: (id % 2 == 0) as u8,
+        }
+    }
+}
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+    let zero = MaterialPoint {
+        id: 0,
+        position: [0.0; 3],
+        stress: [[0.0; 3]; 3],
+        active: 0,
+    };
+
+    // broadcast
+    let mut points = if rank == 0 { (0..N).map(MaterialPoint::new).collect() } else { vec![zero; N] };
+    comm.broadcast(0, &mut points)?;
+    assert_eq!(points[2], MaterialPoint::new(2));
+
+    // send/receive
+    if size > 1 {
+        if rank == 0 {
+            comm.send(&points, 1, 10)?;
+        } else if rank == 1 {
+            let mut received = vec![zero; N];
+            let status = comm.receive(&mut received, 0, 10)?;
+            assert_eq!(status.count, Some(N));
+            assert_eq!(received, points);
+        }
+    }
+
+    // scatter and gather
+    let mut mine = vec![zero; N];
+    if rank == 0 {
+        let all: Vec<_> = (0..N * size).map(MaterialPoint::new).collect();
+        comm.scatter(0, &mut mine, Some(&all))?;
+    } else {
+        comm.scatter(0, &mut mine, None)?;
+    }
+    assert_eq!(mine[1], MaterialPoint::new(rank * N + 1));
+    if rank == 0 {
+        let mut all = vec![zero; N * size];
+        comm.gather(0, Some(&mut all), &mine)?;
+        assert_eq!(all, (0..N * size).map(MaterialPoint::new).collect::<Vec<_>>());
+    } else {
+        comm.gather(0, None, &mine)?;
+    }
+
+    // allgather (with nested structs and tuple structs)
+    let cell = Cell(rank as u8, MaterialPoint::new(rank));
+    let mut cells = vec![Cell(0, zero); size];
+    comm.allgather(&mut cells, &[cell])?;
+    for (r, c) in cells.iter().enumerate() {
+        assert_eq!(c, &Cell(r as u8, MaterialPoint::new(r)));
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
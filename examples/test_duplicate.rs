@@ -0,0 +1,69 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const TAG: i32 = 10;
+
+    // duplicates have the same ranks but isolated messages
+    let mut solver = comm.duplicate()?;
+    let mut assembler = comm.clone();
+    assert_eq!(solver.rank()?, rank);
+    assert_eq!(solver.size()?, size);
+    assert_eq!(comm.compare(&comm)?, MpiComparison::Identical);
+    assert_eq!(comm.compare(&solver)?, MpiComparison::Congruent);
+    assert_eq!(solver.compare(&assembler)?, MpiComparison::Congruent);
+
+    // the same tag in both communicators: each message is received by the right one
+    let next = (rank + 1) % size;
+    let prev = (rank + size - 1) % size;
+    let req_a = unsafe { assembler.isend(&[1.0, 2.0], next, TAG) }?;
+    let req_s = unsafe { solver.isend(&[-3.0], next, TAG) }?;
+    let (x, status) = solver.receive_vec::<f64>(prev as i32, TAG)?;
+    assert_eq!(x, &[-3.0]);
+    assert_eq!(status.count, Some(1));
+    let (y, _) = assembler.receive_vec::<f64>(prev as i32, TAG)?;
+    assert_eq!(y, &[1.0, 2.0]);
+    req_a.wait()?;
+    req_s.wait()?;
+
+    // similar and unequal communicators
+    let reversed = comm.split(Some(0), size - 1 - rank)?.unwrap();
+    let expected = if size == 1 { MpiComparison::Congruent } else { MpiComparison::Similar };
+    assert_eq!(comm.compare(&reversed)?, expected);
+    let alone = comm.split(Some(rank), 0)?.unwrap();
+    if size > 1 {
+        assert_eq!(comm.compare(&alone)?, MpiComparison::Unequal);
+    }
+
+    // non-blocking duplication
+    let pending = comm.idup()?;
+    comm.barrier()?;
+    let mut other = pending.wait()?;
+    assert_eq!(other.rank()?, rank);
+    assert_eq!(comm.compare(&other)?, MpiComparison::Congruent);
+    let mut pending = other.idup()?;
+    while !pending.test()? {}
+    let mut last = pending.wait()?;
+    let mut sum = [0];
+    last.allreduce(&mut sum, &[rank], MpiOpInt::Sum)?;
+    assert_eq!(sum[0], (0..size).sum::<usize>());
+
+    // names
+    assert_eq!(comm.name()?, "MPI_COMM_WORLD");
+    solver.set_name("solver 😊")?;
+    assert_eq!(solver.name()?, "solver 😊");
+    assert_eq!(solver.set_name(&"x".repeat(1000)).err(), Some("the name of the communicator is too long"));
+    assert_eq!(solver.set_name("a\0b").err(), Some("the name must not contain nul characters"));
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
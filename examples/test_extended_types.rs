@@ -0,0 +1,166 @@
+use msgpass::*;
+
+const N: usize = 3;
+
+// Broadcasts, sends/receives, gathers, and scatters using generic code
+fn exchange<T>(comm: &mut Communicator, value: T, zero: T) -> Result<(), StrError>
+where
+    T: MpiData + PartialEq + std::fmt::Debug,
+{
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // broadcast
+    let mut x = if rank == 0 { vec![value; N] } else { vec![zero; N] };
+    comm.broadcast(0, &mut x)?;
+    assert_eq!(x, vec![value; N]);
+
+    // send/receive
+    if rank == 0 {
+        for to in 1..size {
+            comm.send(&x, to, 10)?;
+        }
+    } else {
+        let mut y = vec![zero; N];
+        let status = comm.receive(&mut y, 0, 10)?;
+        assert_eq!(status.count, Some(N));
+        assert_eq!(y, vec![value; N]);
+    }
+
+    // scatter and gather
+    let mut part = vec![zero; N];
+    if rank == 0 {
+        let all = vec![value; N * size];
+        comm.scatter(0, &mut part, Some(&all))?;
+    } else {
+        comm.scatter(0, &mut part, None)?;
+    }
+    assert_eq!(part, vec![value; N]);
+    if rank == 0 {
+        let mut all = vec![zero; N * size];
+        comm.gather(0, Some(&mut all), &part)?;
+        assert_eq!(all, vec![value; N * size]);
+    } else {
+        comm.gather(0, None, &part)?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    exchange(&mut comm, -7_i8, 0)?;
+    exchange(&mut comm, -300_i16, 0)?;
+    exchange(&mut comm, 60000_u16, 0)?;
+
+    // numeric operations on small integers
+    let mut y_i8 = vec![0_i8; N];
+    comm.allreduce(&mut y_i8, &[-1_i8; N], MpiOpInt::Sum)?;
+    assert_eq!(y_i8, vec![-(size as i8); N]);
+
+    let mut y_i16 = vec![0_i16; N];
+    comm.allreduce(&mut y_i16, &[rank as i16; N], MpiOpInt::Max)?;
+    assert_eq!(y_i16, vec![(size - 1) as i16; N]);
+
+    let mut y_u16 = vec![0_u16; N];
+    comm.reduce(0, &mut y_u16, &[(rank + 1) as u16; N], MpiOpInt::Min)?;
+    if rank == 0 {
+        assert_eq!(y_u16, vec![1; N]);
+    }
+
+    // numeric operations on unsigned bytes (counters)
+    let mut count = vec![0_u8; N];
+    comm.allreduce(&mut count, &[1_u8; N], MpiOpByte::Sum)?;
+    assert_eq!(count, vec![size as u8; N]);
+
+    let mut y_u8 = vec![0_u8; N];
+    comm.allreduce(&mut y_u8, &[(rank + 1) as u8; N], MpiOpByte::Max)?;
+    assert_eq!(y_u8, vec![size as u8; N]);
+
+    // booleans (sent as MPI_C_BOOL and converted on arrival)
+    let mut flags = if rank == 0 { vec![true, false, true] } else { vec![false; N] };
+    comm.broadcast_bools(0, &mut flags)?;
+    assert_eq!(flags, vec![true, false, true]);
+    if rank == 0 {
+        for to in 1..size {
+            comm.send_bools(&flags, to, 20)?;
+            comm.send(&[2_u8, 0, 255], to, 21)?; // any non-zero byte is true
+        }
+    } else {
+        let mut y = vec![false; N];
+        comm.receive_bools(&mut y, 0, 20)?;
+        assert_eq!(y, flags);
+        let mut z = vec![false; N];
+        comm.receive_bools(&mut z, 0, 21)?;
+        assert_eq!(z, vec![true, false, true]);
+    }
+    let mut part = vec![false; N];
+    if rank == 0 {
+        let all: Vec<_> = (0..N * size).map(|i| i % 2 == 0).collect();
+        comm.scatter_bools(0, &mut part, Some(&all))?;
+        let mut back = vec![false; N * size];
+        comm.gather_bools(0, Some(&mut back), &part)?;
+        assert_eq!(back, all);
+    } else {
+        comm.scatter_bools(0, &mut part, None)?;
+        comm.gather_bools(0, None, &part)?;
+    }
+    let correct: Vec<_> = (rank * N..(rank + 1) * N).map(|i| i % 2 == 0).collect();
+    assert_eq!(part, correct);
+
+    // logical operations on flags
+    let converged = vec![true, rank == 0, rank != 0];
+    let mut all = vec![false; N];
+    comm.allreduce_bools(&mut all, &converged, MpiOpBool::And)?;
+    assert_eq!(all, vec![true, size == 1, false]);
+
+    let mut any = vec![false; N];
+    comm.allreduce_bools(&mut any, &converged, MpiOpBool::Or)?;
+    assert_eq!(any, vec![true, true, size > 1]);
+
+    let mut one = vec![false; N];
+    comm.reduce_bools(0, &mut one, &converged, MpiOpBool::Xor)?;
+    if rank == 0 {
+        assert_eq!(one, vec![size % 2 == 1, true, (size - 1) % 2 == 1]);
+    }
+
+    // chars (sent as u32 and validated on arrival)
+    let mut letters = if rank == 0 { vec!['π', 'a', '😊'] } else { vec![' '; N] };
+    comm.broadcast_chars(0, &mut letters)?;
+    assert_eq!(letters, vec!['π', 'a', '😊']);
+    if rank == 0 {
+        for to in 1..size {
+            comm.send_chars(&letters, to, 30)?;
+            comm.send(&[0xD800_u32, 0, 0], to, 31)?; // surrogate: not a valid char
+        }
+    } else {
+        let mut y = vec![' '; N];
+        comm.receive_chars(&mut y, 0, 30)?;
+        assert_eq!(y, letters);
+        let mut z = vec![' '; N];
+        assert_eq!(comm.receive_chars(&mut z, 0, 31).err(), Some("the received data contains an invalid char"));
+    }
+    let mut letter = [' '];
+    if rank == 0 {
+        let all: Vec<_> = (0..size).map(|r| char::from(b'a' + r as u8)).collect();
+        comm.scatter_chars(0, &mut letter, Some(&all))?;
+        let mut back = vec![' '; size];
+        comm.gather_chars(0, Some(&mut back), &letter)?;
+        assert_eq!(back, all);
+    } else {
+        comm.scatter_chars(0, &mut letter, None)?;
+        comm.gather_chars(0, None, &letter)?;
+    }
+    assert_eq!(letter, [char::from(b'a' + rank as u8)]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // process r contributes r+1 values equal to r
+    let counts: Vec<usize> = (0..size).map(|r| r + 1).collect();
+    let displs: Vec<usize> = (0..size).map(|r| r * (r + 1) / 2).collect();
+    let total = size * (size + 1) / 2;
+    let correct: Vec<i32> = (0..size).flat_map(|r| vec![r as i32; r + 1]).collect();
+    let orig = vec![rank as i32; rank + 1];
+
+    // gatherv
+    if rank == 0 {
+        let mut dest = vec![0_i32; total];
+        comm.gatherv(0, Some(&mut dest), &counts, &displs, &orig)?;
+        assert_eq!(dest, correct);
+    } else {
+        comm.gatherv(0, None, &[], &[], &orig)?;
+    }
+
+    // allgatherv
+    let mut dest = vec![0_i32; total];
+    comm.allgatherv(&mut dest, &counts, &displs, &orig)?;
+    assert_eq!(dest, correct);
+
+    // scatterv
+    let mut part = vec![0_i32; rank + 1];
+    if rank == 0 {
+        comm.scatterv(0, &mut part, Some(&correct), &counts, &displs)?;
+    } else {
+        comm.scatterv(0, &mut part, None, &[], &[])?;
+    }
+    assert_eq!(part, orig);
+
+    // gather with automatic counts
+    let root = size - 1;
+    let x = vec![1.5 * rank as f64; rank];
+    let res = comm.gather_vec(root, &x)?;
+    if rank == root {
+        let parts = res.unwrap();
+        assert_eq!(parts.len(), size);
+        for (r, part) in parts.iter().enumerate() {
+            assert_eq!(part, &vec![1.5 * r as f64; r]);
+        }
+    } else {
+        assert_eq!(res, None);
+    }
+    let (data, offsets) = comm.gather_flat(root, &x)?;
+    if rank == root {
+        assert_eq!(offsets.len(), size + 1);
+        for r in 0..size {
+            assert_eq!(&data[offsets[r]..offsets[r + 1]], &vec![1.5 * r as f64; r]);
+        }
+    } else {
+        assert_eq!(data.len(), 0);
+        assert_eq!(offsets.len(), 0);
+    }
+
+    // allgather with automatic counts
+    let parts = comm.allgather_vec(&x)?;
+    assert_eq!(parts.len(), size);
+    for (r, part) in parts.iter().enumerate() {
+        assert_eq!(part, &vec![1.5 * r as f64; r]);
+    }
+    let (data, offsets) = comm.allgather_flat(&x)?;
+    assert_eq!(offsets[size], size * (size - 1) / 2);
+    for r in 0..size {
+        assert_eq!(&data[offsets[r]..offsets[r + 1]], &vec![1.5 * r as f64; r]);
+    }
+
+    // scatter with automatic counts
+    let part = if rank == 0 {
+        let all: Vec<Vec<u8>> = (0..size).map(|r| vec![r as u8; 2 * r]).collect();
+        comm.scatter_vec(0, Some(&all))?
+    } else {
+        comm.scatter_vec(0, None)?
+    };
+    assert_eq!(part, vec![rank as u8; 2 * rank]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
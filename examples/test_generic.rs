@@ -0,0 +1,95 @@
+use msgpass::*;
+use num_complex::Complex64;
+
+const N: usize = 3;
+
+// Computes the sum over all processors using generic code
+fn sum_all<T>(comm: &mut Communicator, x: &[T]) -> Result<Vec<T>, StrError>
+where
+    T: MpiReducible<Op = MpiOpReal> + From<f32>,
+{
+    let mut y = vec![T::from(0.0); x.len()];
+    comm.allreduce(&mut y, x, MpiOpReal::Sum)?;
+    Ok(y)
+}
+
+// Broadcasts, scatters, and gathers using generic code
+fn exchange<T>(comm: &mut Communicator, value: T, zero: T) -> Result<(), StrError>
+where
+    T: MpiData + PartialEq + std::fmt::Debug,
+{
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // broadcast
+    let mut x = if rank == 0 { vec![value; N] } else { vec![zero; N] };
+    comm.broadcast(0, &mut x)?;
+    assert_eq!(x, vec![value; N]);
+
+    // send/receive
+    if rank == 0 {
+        for to in 1..size {
+            comm.send(&x, to, 10)?;
+        }
+    } else {
+        let mut y = vec![zero; N];
+        comm.receive(&mut y, 0, 10)?;
+        assert_eq!(y, vec![value; N]);
+    }
+
+    // scatter and gather
+    let mut part = vec![zero; N];
+    if rank == 0 {
+        let all = vec![value; N * size];
+        comm.scatter(0, &mut part, Some(&all))?;
+    } else {
+        comm.scatter(0, &mut part, None)?;
+    }
+    assert_eq!(part, vec![value; N]);
+    if rank == 0 {
+        let mut all = vec![zero; N * size];
+        comm.gather(0, Some(&mut all), &part)?;
+        assert_eq!(all, vec![value; N * size]);
+    } else {
+        comm.gather(0, None, &part)?;
+    }
+
+    // allgather
+    let mut all = vec![zero; N * size];
+    comm.allgather(&mut all, &part)?;
+    assert_eq!(all, vec![value; N * size]);
+    Ok(())
+}
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    let x_f32 = vec![1.0_f32; N];
+    let x_f64 = vec![1.0_f64; N];
+    assert_eq!(sum_all(&mut comm, &x_f32)?, vec![size as f32; N]);
+    assert_eq!(sum_all(&mut comm, &x_f64)?, vec![size as f64; N]);
+
+    let x_i32 = vec![1 + rank as i32; N];
+    let mut y_i32 = vec![0_i32; N];
+    comm.reduce(0, &mut y_i32, &x_i32, MpiOpInt::Max)?;
+    if rank == 0 {
+        assert_eq!(y_i32, vec![size as i32; N]);
+    }
+
+    exchange(&mut comm, 123_i32, 0)?;
+    exchange(&mut comm, 123_usize, 0)?;
+    exchange(&mut comm, 1.5_f64, 0.0)?;
+    exchange(&mut comm, Complex64::new(1.0, 2.0), Complex64::new(0.0, 0.0))?;
+    exchange(&mut comm, 7_u8, 0)?;
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    let world = comm.group()?;
+    assert_eq!(world.size()?, size);
+    assert_eq!(world.rank()?, Some(rank));
+
+    // even and odd ranks
+    let evens: Vec<_> = (0..size).step_by(2).collect();
+    let even = world.include(&evens)?;
+    let odd = world.exclude(&evens)?;
+    assert_eq!(even.size()?, evens.len());
+    assert_eq!(odd.size()?, size - evens.len());
+    if rank % 2 == 0 {
+        assert_eq!(even.rank()?, Some(rank / 2));
+        assert_eq!(odd.rank()?, None);
+    } else {
+        assert_eq!(even.rank()?, None);
+        assert_eq!(odd.rank()?, Some(rank / 2));
+    }
+
+    // set operations
+    let all = even.union(&odd)?;
+    assert_eq!(all.size()?, size);
+    assert_eq!(all.compare(&world)?, if size > 2 { MpiComparison::Similar } else { MpiComparison::Identical });
+    assert_eq!(even.intersection(&odd)?.size()?, 0);
+    assert_eq!(world.difference(&odd)?.compare(&even)?, MpiComparison::Identical);
+    assert_eq!(world.compare(&comm.group()?)?, MpiComparison::Identical);
+    assert_eq!(even.compare(&odd)?, MpiComparison::Unequal);
+
+    // ranges (the last rank first, then the others in order)
+    let last = size - 1;
+    let mut ranges = vec![(last, last, 1)];
+    if size > 1 {
+        ranges.push((0, last - 1, 1));
+    }
+    let rotated = world.range_include(&ranges)?;
+    assert_eq!(rotated.rank()?, Some((rank + 1) % size));
+    let backwards = world.range_include(&[(last, 0, -1)])?;
+    assert_eq!(backwards.rank()?, Some(last - rank));
+    assert_eq!(world.range_include(&[(0, last, 0)]).err(), Some("the stride of the ranges must not be zero"));
+
+    // translate the ranks of a subset to world ranks
+    let sub_ranks: Vec<_> = (0..odd.size()?).collect();
+    let world_ranks = odd.translate_ranks(&sub_ranks, &world)?;
+    let correct: Vec<_> = (0..size).filter(|r| r % 2 != 0).map(Some).collect();
+    assert_eq!(world_ranks, correct);
+    let world_ranks: Vec<_> = (0..size).collect();
+    let even_ranks = world.translate_ranks(&world_ranks, &even)?;
+    let correct: Vec<_> = (0..size).map(|r| if r % 2 == 0 { Some(r / 2) } else { None }).collect();
+    assert_eq!(even_ranks, correct);
+
+    // communicator from group (all processes call it)
+    match comm.from_group(&even)? {
+        Some(mut sub) => {
+            assert!(rank % 2 == 0);
+            assert_eq!(sub.rank()?, rank / 2);
+            assert_eq!(sub.size()?, evens.len());
+            let sub_world = sub.group()?.translate_ranks(&[sub.rank()?], &world)?;
+            assert_eq!(sub_world, &[Some(rank)]);
+        }
+        None => assert!(rank % 2 != 0),
+    }
+
+    // communicator from group (only the members call it)
+    if rank % 2 == 0 {
+        let mut sub = comm.from_group_members(&even, 5)?;
+        let mut sum = [0];
+        sub.allreduce(&mut sum, &[rank], MpiOpInt::Sum)?;
+        assert_eq!(sum[0], evens.iter().sum::<usize>());
+    } else {
+        assert_eq!(comm.from_group_members(&even, 5).err(), Some("this process is not a member of the group"));
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use msgpass::*;
+use num_complex::Complex64;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+
+    // allreduce
+    let mut x = vec![1.0 + rank as f64; N];
+    comm.allreduce_in_place(&mut x, MpiOpReal::Sum)?;
+    assert_eq!(x, vec![(size * (size + 1) / 2) as f64; N]);
+
+    let mut z = vec![Complex64::new(1.0, 2.0); N];
+    comm.allreduce_in_place(&mut z, MpiOpComplex::Sum)?;
+    assert_eq!(z, vec![Complex64::new(size as f64, 2.0 * size as f64); N]);
+
+    // reduce
+    let root = size - 1;
+    let mut y = vec![rank as i32; N];
+    comm.reduce_in_place(root, &mut y, MpiOpInt::Max)?;
+    if rank == root {
+        assert_eq!(y, vec![(size - 1) as i32; N]);
+    } else {
+        assert_eq!(y, vec![rank as i32; N]);
+    }
+
+    // gather
+    if rank == root {
+        let mut all = vec![0_u64; N * size];
+        all[N * rank..N * (rank + 1)].fill(rank as u64);
+        comm.gather_in_place(root, &mut all)?;
+        let correct: Vec<u64> = (0..size).flat_map(|r| vec![r as u64; N]).collect();
+        assert_eq!(all, correct);
+    } else {
+        let mut mine = vec![rank as u64; N];
+        comm.gather_in_place(root, &mut mine)?;
+    }
+
+    // allgather
+    let mut all = vec![0_u8; N * size];
+    all[N * rank..N * (rank + 1)].fill(rank as u8);
+    comm.allgather_in_place(&mut all)?;
+    let correct: Vec<u8> = (0..size).flat_map(|r| vec![r as u8; N]).collect();
+    assert_eq!(all, correct);
+
+    // length validation
+    if size > 1 {
+        let mut wrong = vec![0_u8; N * size + 1];
+        assert_eq!(comm.allgather_in_place(&mut wrong).err(), Some("data.len() must be a multiple of the number of processors"));
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+    const TAG: i32 = 10;
+
+    // ring exchange: send to the next rank and receive from the previous rank
+    let next = (rank + 1) % size;
+    let prev = (rank + size - 1) % size;
+
+    let x = vec![1000.0 + rank as f64; N];
+    let mut y = vec![0.0; N];
+    let recv = unsafe { comm.irecv(&mut y, prev as i32, TAG) }?;
+    let send = unsafe { comm.isend(&x, next, TAG) }?;
+    let status = recv.wait()?;
+    send.wait()?;
+    assert_eq!(status.source, prev as i32);
+    assert_eq!(status.tag, TAG);
+    assert_eq!(status.error, 0);
+    assert_eq!(status.count, Some(N));
+    assert_eq!(y, vec![1000.0 + prev as f64; N]);
+
+    // test until completion (and use any source/tag)
+    let u = vec![rank as i32; N];
+    let mut v = vec![0_i32; N];
+    let mut recv = unsafe { comm.irecv(&mut v, -1, -1) }?;
+    let mut send = unsafe { comm.isend(&u, next, TAG + 1) }?;
+    let status = loop {
+        if let Some(status) = recv.test()? {
+            break status;
+        }
+    };
+    while send.test()?.is_none() {}
+    drop(recv);
+    assert_eq!(status.source, prev as i32);
+    assert_eq!(status.tag, TAG + 1);
+    assert_eq!(v, vec![prev as i32; N]);
+
+    // requests are waited for when dropped
+    {
+        let z = vec![rank as u8; N];
+        let mut w = vec![0_u8; N];
+        let _recv = unsafe { comm.irecv(&mut w, prev as i32, TAG + 2) }?;
+        let _send = unsafe { comm.isend(&z, next, TAG + 2) }?;
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use msgpass::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+enum Solver {
+    #[default]
+    Direct,
+    Iterative {
+        tolerance: f64,
+        max_iterations: usize,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    solver: Solver,
+    params: HashMap<String, Vec<f64>>,
+}
+
+impl Config {
+    fn new(rank: usize) -> Self {
+        let mut params = HashMap::new();
+        params.insert("young".to_string(), vec![1000.0 * (rank + 1) as f64]);
+        params.insert("poisson".to_string(), vec![0.25; rank]);
+        Config {
+            name: format!("config 😊 of rank #{}", rank).repeat(rank + 1),
+            solver: if rank % 2 == 0 { Solver::Direct } else { Solver::Iterative { tolerance: 1e-8, max_iterations: 100 * rank } },
+            params,
+        }
+    }
+}
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const ROOT: usize = 0;
+    const TAG: i32 = 10;
+
+    // send/receive (the length depends on the destination)
+    if rank == ROOT {
+        for to in 1..size {
+            comm.send_object(&Config::new(to), to, TAG)?;
+        }
+    } else {
+        let (config, status) = comm.receive_object::<Config>(ROOT as i32, TAG)?;
+        assert_eq!(status.source, ROOT as i32);
+        assert_eq!(status.tag, TAG);
+        assert_eq!(config, Config::new(rank));
+    }
+
+    // broadcast
+    let mut config = if rank == ROOT { Config::new(size + 1) } else { Config::default() };
+    comm.broadcast_object(ROOT, &mut config)?;
+    assert_eq!(config, Config::new(size + 1));
+
+    // gather
+    let all = comm.gather_objects(ROOT, &Config::new(rank))?;
+    if rank == ROOT {
+        let correct: Vec<_> = (0..size).map(Config::new).collect();
+        assert_eq!(all, Some(correct));
+    } else {
+        assert_eq!(all, None);
+    }
+
+    // allgather
+    let words = comm.allgather_objects(&"word".repeat(rank))?;
+    let correct: Vec<_> = (0..size).map(|r| "word".repeat(r)).collect();
+    assert_eq!(words, correct);
+
+    // scatter
+    let solver = if rank == ROOT {
+        let solvers: Vec<_> = (0..size).map(|r| Config::new(r).solver).collect();
+        comm.scatter_objects(ROOT, Some(&solvers))?
+    } else {
+        comm.scatter_objects::<Solver>(ROOT, None)?
+    };
+    assert_eq!(solver, Config::new(rank).solver);
+
+    mpi_finalize()?;
+
+    if rank == ROOT {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+    const TAG: i32 = 10;
+    const NSTEP: usize = 4;
+
+    // ring exchange: send to the next rank and receive from the previous rank
+    let next = (rank + 1) % size;
+    let prev = (rank + size - 1) % size;
+
+    let mut x = vec![0.0; N];
+    let mut y = vec![0.0; N];
+    let mut send = unsafe { comm.send_init(&mut x, next, TAG)? };
+    let mut recv = unsafe { comm.recv_init(&mut y, prev as i32, TAG)? };
+    assert!(!send.is_active());
+    assert!(!recv.is_active());
+    for step in 0..NSTEP {
+        send.data_mut()?.fill((100 * step + rank) as f64);
+        recv.start()?;
+        send.start()?;
+        assert!(recv.is_active());
+        assert_eq!(recv.data().err(), Some("cannot access the buffer of an active persistent request"));
+        assert_eq!(send.start().err(), Some("the persistent request is already active"));
+        let status = recv.wait()?;
+        send.wait()?;
+        assert_eq!(status.source, prev as i32);
+        assert_eq!(status.tag, TAG);
+        assert_eq!(status.count, Some(N));
+        assert_eq!(recv.data()?, &vec![(100 * step + prev) as f64; N]);
+    }
+    drop(send);
+    drop(recv);
+
+    // start all send requests at once, with buffers of different types (and test until completion)
+    let mut u = vec![0_i32; N];
+    let mut v = vec![0.0_f64; N];
+    let mut w = vec![0_i32; N];
+    let mut z = vec![0.0_f64; N];
+    let mut send_u = unsafe { comm.send_init(&mut u, next, TAG + 1)? };
+    let mut send_v = unsafe { comm.send_init(&mut v, next, TAG + 2)? };
+    let mut recv_w = unsafe { comm.recv_init(&mut w, prev as i32, TAG + 1)? };
+    let mut recv_z = unsafe { comm.recv_init(&mut z, prev as i32, TAG + 2)? };
+    for step in 0..NSTEP {
+        send_u.data_mut()?.fill((step + rank) as i32);
+        send_v.data_mut()?.fill(-((step + rank) as f64));
+        start_all(&mut [&mut send_u, &mut send_v])?;
+        assert_eq!(start_all(&mut [&mut send_u]).err(), Some("all persistent requests must be inactive"));
+        recv_z.start()?;
+        recv_w.start()?;
+        assert!(recv_w.is_active() && recv_z.is_active());
+        let status = loop {
+            if let Some(status) = recv_w.test()? {
+                break status;
+            }
+        };
+        assert_eq!(status.tag, TAG + 1);
+        assert_eq!(recv_z.wait()?.tag, TAG + 2);
+        assert_eq!(recv_w.data()?, &vec![(step + prev) as i32; N]);
+        assert_eq!(recv_z.data()?, &vec![-((step + prev) as f64); N]);
+        send_u.wait()?;
+        send_v.wait()?;
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const TAG_PROBE: i32 = 10;
+    const TAG_VEC: i32 = 20;
+
+    if rank == 0 {
+        for to in 1..size {
+            // the length of the message depends on the destination
+            let x: Vec<f64> = (0..(2 + to)).map(|i| i as f64).collect();
+            comm.send(&x, to, TAG_PROBE)?;
+            let u: Vec<i32> = (0..(5 * to)).map(|i| i as i32).collect();
+            comm.send(&u, to, TAG_VEC)?;
+        }
+    } else {
+        // probe then receive into a buffer with the correct size
+        let status = comm.probe::<f64>(0, TAG_PROBE)?;
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_PROBE);
+        assert_eq!(status.count, Some(2 + rank));
+        let mut y = vec![0.0; status.count.unwrap()];
+        comm.receive(&mut y, 0, TAG_PROBE)?;
+        let correct: Vec<f64> = (0..(2 + rank)).map(|i| i as f64).collect();
+        assert_eq!(y, correct);
+
+        // non-blocking probe with any source and any tag
+        let status = loop {
+            if let Some(status) = comm.iprobe::<i32>(-1, -1)? {
+                break status;
+            }
+        };
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_VEC);
+        assert_eq!(status.count, Some(5 * rank));
+
+        // receive into a newly allocated vector
+        let (v, status) = comm.receive_vec::<i32>(-1, -1)?;
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_VEC);
+        assert_eq!(status.count, Some(5 * rank));
+        let correct: Vec<i32> = (0..(5 * rank)).map(|i| i as i32).collect();
+        assert_eq!(v, correct);
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
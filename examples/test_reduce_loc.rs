@@ -0,0 +1,53 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // the maximum residual is on the last rank and the minimum time step is on the first rank
+    let residual = [1.0 + rank as f64, -(rank as f64)];
+    let res = comm.allreduce_max_loc(&residual)?;
+    assert_eq!(res, &[(size as f64, size - 1), (0.0, 0)]);
+
+    let dt = [0.1_f32 * (1 + rank) as f32];
+    let res = comm.allreduce_min_loc(&dt)?;
+    assert_eq!(res, &[(0.1, 0)]);
+
+    // repeated values select the lowest rank
+    let res = comm.allreduce_max_loc(&[7_i32])?;
+    assert_eq!(res, &[(7, 0)]);
+
+    // arbitrary indices (e.g., global node numbers)
+    let orig = [(rank as i32 % 2, 100 + rank)];
+    let mut dest = [(0, 0)];
+    comm.allreduce_loc(&mut dest, &orig, MpiOpLoc::Min)?;
+    assert_eq!(dest, [(0, 100)]);
+
+    // reduce to root
+    let root = size - 1;
+    let orig = [(-1.5 * rank as f64, rank), (2.0, rank)];
+    let mut dest = [(0.0, 0); 2];
+    comm.reduce_loc(root, &mut dest, &orig, MpiOpLoc::Min)?;
+    if rank == root {
+        assert_eq!(dest, [(-1.5 * (size - 1) as f64, size - 1), (2.0, 0)]);
+    } else {
+        assert_eq!(dest, [(0.0, 0); 2]);
+    }
+
+    // with c_long values (64-bit Linux/macOS)
+    #[cfg(all(target_pointer_width = "64", not(windows)))]
+    {
+        let res = comm.allreduce_max_loc(&[rank as i64 * 1_000_000_000_000])?;
+        assert_eq!(res, &[((size - 1) as i64 * 1_000_000_000_000, size - 1)]);
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
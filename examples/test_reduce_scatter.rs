@@ -0,0 +1,54 @@
+use msgpass::*;
+use num_complex::Complex32;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 2;
+
+    // reduce_scatter_block: every process contributes 1 to each entry of the global vector
+    let orig = vec![1.0; N * size];
+    let mut dest = vec![0.0; N];
+    comm.reduce_scatter_block(&mut dest, &orig, MpiOpReal::Sum)?;
+    assert_eq!(dest, vec![size as f64; N]);
+
+    // with integers and max
+    let orig: Vec<i64> = (0..N * size).map(|i| (i * rank) as i64).collect();
+    let mut dest = vec![0_i64; N];
+    comm.reduce_scatter_block(&mut dest, &orig, MpiOpInt::Max)?;
+    let correct: Vec<i64> = (0..N).map(|k| ((N * rank + k) * (size - 1)) as i64).collect();
+    assert_eq!(dest, correct);
+
+    // length validation
+    let mut wrong = vec![0.0; N + 1];
+    let orig = vec![1.0; N * size];
+    assert_eq!(comm.reduce_scatter_block(&mut wrong, &orig, MpiOpReal::Sum).err(), Some("orig.len() must equal the number of processors times dest.len()"));
+
+    // reduce_scatter: process r receives r+1 entries of the global vector
+    let counts: Vec<usize> = (0..size).map(|r| r + 1).collect();
+    let total = size * (size + 1) / 2;
+    let orig: Vec<Complex32> = (0..total).map(|i| Complex32::new(i as f32, 1.0)).collect();
+    let mut dest = vec![Complex32::new(0.0, 0.0); rank + 1];
+    comm.reduce_scatter(&mut dest, &orig, &counts, MpiOpComplex::Sum)?;
+    let start = rank * (rank + 1) / 2;
+    let correct: Vec<Complex32> = (start..start + rank + 1).map(|i| Complex32::new((i * size) as f32, size as f32)).collect();
+    assert_eq!(dest, correct);
+
+    // with bytes
+    let orig = vec![1_u8 << rank.min(7); total];
+    let mut dest = vec![0_u8; rank + 1];
+    comm.reduce_scatter(&mut dest, &orig, &counts, MpiOpByte::Or)?;
+    let correct = (0..size).fold(0_u8, |acc, r| acc | (1_u8 << r.min(7)));
+    assert_eq!(dest, vec![correct; rank + 1]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+    const TAG: i32 = 10;
+
+    let next = (rank + 1) % size;
+    let prev = (rank + size - 1) % size;
+    let x = vec![1000 + rank as i64; N];
+
+    // wait all
+    let mut y = vec![0_i64; N];
+    let mut set = RequestSet::new()?;
+    assert!(set.is_empty());
+    assert_eq!(set.push(unsafe { comm.irecv(&mut y, prev as i32, TAG) }?)?, 0);
+    assert_eq!(set.push(unsafe { comm.isend(&x, next, TAG) }?)?, 1);
+    assert_eq!(set.len(), 2);
+    let statuses = set.wait_all()?;
+    assert_eq!(statuses.len(), 2);
+    assert_eq!(statuses[0].source, prev as i32);
+    assert_eq!(statuses[0].tag, TAG);
+    drop(set);
+    assert_eq!(y, vec![1000 + prev as i64; N]);
+
+    // wait any
+    let mut y = vec![0_i64; N];
+    let mut set = RequestSet::new()?;
+    set.push(unsafe { comm.irecv(&mut y, prev as i32, TAG + 1) }?)?;
+    set.push(unsafe { comm.isend(&x, next, TAG + 1) }?)?;
+    let mut completed = vec![false; 2];
+    while let Some((index, _)) = set.wait_any()? {
+        completed[index] = true;
+    }
+    assert_eq!(completed, &[true, true]);
+    drop(set);
+    assert_eq!(y, vec![1000 + prev as i64; N]);
+
+    // wait some
+    let mut y = vec![0_i64; N];
+    let mut set = RequestSet::new()?;
+    set.push(unsafe { comm.irecv(&mut y, -1, -1) }?)?;
+    set.push(unsafe { comm.isend(&x, next, TAG + 2) }?)?;
+    let mut count = 0;
+    loop {
+        let res = set.wait_some()?;
+        if res.is_empty() {
+            break;
+        }
+        for (index, status) in res {
+            if index == 0 {
+                assert_eq!(status.source, prev as i32);
+                assert_eq!(status.tag, TAG + 2);
+            }
+            count += 1;
+        }
+    }
+    assert_eq!(count, 2);
+    drop(set);
+    assert_eq!(y, vec![1000 + prev as i64; N]);
+
+    // test all
+    let mut y = vec![0_i64; N];
+    let mut set = RequestSet::new()?;
+    set.push(unsafe { comm.irecv(&mut y, prev as i32, TAG + 3) }?)?;
+    set.push(unsafe { comm.isend(&x, next, TAG + 3) }?)?;
+    let statuses = loop {
+        if let Some(statuses) = set.test_all()? {
+            break statuses;
+        }
+    };
+    assert_eq!(statuses[0].source, prev as i32);
+    drop(set);
+    assert_eq!(y, vec![1000 + prev as i64; N]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
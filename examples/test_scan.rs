@@ -0,0 +1,63 @@
+use msgpass::*;
+use num_complex::Complex64;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+
+    // global numbering: process r owns r+1 nodes
+    let count = [rank + 1];
+    let mut end = [0_usize];
+    let mut start = [0_usize];
+    comm.scan(&mut end, &count, MpiOpInt::Sum)?;
+    comm.exscan(&mut start, &count, MpiOpInt::Sum)?;
+    assert_eq!(end[0], (rank + 1) * (rank + 2) / 2);
+    assert_eq!(start[0], rank * (rank + 1) / 2);
+    assert_eq!(end[0] - start[0], rank + 1);
+
+    // integers with max
+    let x = vec![(size - rank) as i32; N];
+    let mut y = vec![-1_i32; N];
+    comm.scan(&mut y, &x, MpiOpInt::Max)?;
+    assert_eq!(y, vec![size as i32; N]);
+    comm.exscan(&mut y, &x, MpiOpInt::Max)?;
+    if rank == 0 {
+        assert_eq!(y, vec![0; N]);
+    } else {
+        assert_eq!(y, vec![size as i32; N]);
+    }
+
+    // reals with product
+    let x = vec![2.0; N];
+    let mut y = vec![-1.0; N];
+    comm.scan(&mut y, &x, MpiOpReal::Prod)?;
+    assert_eq!(y, vec![f64::powi(2.0, rank as i32 + 1); N]);
+    comm.exscan(&mut y, &x, MpiOpReal::Prod)?;
+    if rank == 0 {
+        assert_eq!(y, vec![0.0; N]);
+    } else {
+        assert_eq!(y, vec![f64::powi(2.0, rank as i32); N]);
+    }
+
+    // complex numbers with sum
+    let x = vec![Complex64::new(1.0, rank as f64); N];
+    let mut y = vec![Complex64::new(-1.0, -1.0); N];
+    comm.scan(&mut y, &x, MpiOpComplex::Sum)?;
+    let im = (rank * (rank + 1) / 2) as f64;
+    assert_eq!(y, vec![Complex64::new((rank + 1) as f64, im); N]);
+    comm.exscan(&mut y, &x, MpiOpComplex::Sum)?;
+    let im = (rank * (rank + 1) / 2 - rank) as f64;
+    assert_eq!(y, vec![Complex64::new(rank as f64, im); N]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
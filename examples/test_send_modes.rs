@@ -0,0 +1,70 @@
+use msgpass::*;
+use num_complex::Complex32;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+    const TAG_SSEND: i32 = 10;
+    const TAG_BSEND: i32 = 20;
+    const TAG_RSEND: i32 = 30;
+
+    // synchronous send
+    if rank == 0 {
+        let x = vec![1000_u64; N];
+        for to in 1..size {
+            comm.ssend(&x, to, TAG_SSEND)?;
+        }
+    } else {
+        let mut y = vec![0_u64; N];
+        comm.receive(&mut y, 0, TAG_SSEND)?;
+        assert_eq!(y, vec![1000_u64; N]);
+    }
+
+    // buffered send
+    let bytes = comm.bsend_size::<Complex32>(N)?;
+    assert!(bytes >= N * std::mem::size_of::<Complex32>());
+    let mut buffer = AttachedBuffer::new(size * bytes)?;
+    assert_eq!(buffer.size(), size * bytes);
+    assert_eq!(AttachedBuffer::new(8).err(), Some("another buffer is already attached"));
+    if rank == 0 {
+        let x = vec![Complex32::new(1.0, 2.0); N];
+        for to in 1..size {
+            comm.bsend(&x, to, TAG_BSEND)?;
+        }
+    } else {
+        let mut y = vec![Complex32::new(0.0, 0.0); N];
+        comm.receive(&mut y, 0, TAG_BSEND)?;
+        assert_eq!(y, vec![Complex32::new(1.0, 2.0); N]);
+    }
+    buffer.detach()?;
+    assert_eq!(AttachedBuffer::new(i32::MAX as usize + 1).err(), Some("the size of the buffer must not exceed i32::MAX"));
+    buffer = AttachedBuffer::new(bytes)?; // can attach again after detaching (or after a failed attempt)
+    drop(buffer);
+
+    // ready send (the receive is posted before the barrier)
+    if rank == 0 {
+        comm.barrier()?;
+        let x = vec![1.5_f32; N];
+        for to in 1..size {
+            comm.rsend(&x, to, TAG_RSEND)?;
+        }
+    } else {
+        let mut y = vec![0_f32; N];
+        let request = unsafe { comm.irecv(&mut y, 0, TAG_RSEND) }?;
+        comm.barrier()?;
+        request.wait()?;
+        assert_eq!(y, vec![1.5_f32; N]);
+    }
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
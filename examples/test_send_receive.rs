@@ -68,55 +68,75 @@ fn main() -> Result<(), StrError> {
             comm.send_bytes(&x_byt, to, TAG_BYT)?;
         }
     } else {
-        comm.receive_i32(&mut y_i32, 0, TAG_I32)?;
+        let status = comm.receive_i32(&mut y_i32, 0, TAG_I32)?;
         assert_eq!(&y_i32, &x_i32);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_I32, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_I32);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_i64(&mut y_i64, 0, TAG_I64)?;
+        let status = comm.receive_i64(&mut y_i64, 0, TAG_I64)?;
         assert_eq!(&y_i64, &x_i64);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_I64, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_I64);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_u32(&mut y_u32, 0, TAG_U32)?;
+        let status = comm.receive_u32(&mut y_u32, 0, TAG_U32)?;
         assert_eq!(&y_u32, &x_u32);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_U32, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_U32);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_u64(&mut y_u64, 0, TAG_U64)?;
+        let status = comm.receive_u64(&mut y_u64, 0, TAG_U64)?;
         assert_eq!(&y_u64, &x_u64);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_U64, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_U64);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_usize(&mut y_usz, 0, TAG_USZ)?;
+        let status = comm.receive_usize(&mut y_usz, 0, TAG_USZ)?;
         assert_eq!(&y_usz, &x_usz);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_USZ, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_USZ);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_f32(&mut y_f32, 0, TAG_F32)?;
+        let status = comm.receive_f32(&mut y_f32, 0, TAG_F32)?;
         assert_eq!(&y_f32, &x_f32);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_F32, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_F32);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_f64(&mut y_f64, 0, TAG_F64)?;
+        let status = comm.receive_f64(&mut y_f64, 0, TAG_F64)?;
         assert_eq!(&y_f64, &x_f64);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_F64, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_F64);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_c32(&mut y_c32, 0, TAG_C32)?;
+        let status = comm.receive_c32(&mut y_c32, 0, TAG_C32)?;
         assert_eq!(&y_c32, &x_c32);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_C32, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_C32);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_c64(&mut y_c64, 0, TAG_C64)?;
+        let status = comm.receive_c64(&mut y_c64, 0, TAG_C64)?;
         assert_eq!(&y_c64, &x_c64);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_C64, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_C64);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(N));
 
-        comm.receive_bytes(&mut y_byt, 0, TAG_BYT)?;
+        let status = comm.receive_bytes(&mut y_byt, 0, TAG_BYT)?;
         assert_eq!(&y_byt, &x_byt);
-        let res = comm.get_receive_status();
-        assert_eq!(res, (0, TAG_BYT, 0));
+        assert_eq!(status.source, 0);
+        assert_eq!(status.tag, TAG_BYT);
+        assert_eq!(status.error, 0);
+        assert_eq!(status.count, Some(x_byt.len()));
         assert_eq!(bytes_to_string_lossy(&y_byt), "💖");
     }
 
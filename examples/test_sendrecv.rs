@@ -0,0 +1,49 @@
+use msgpass::*;
+use num_complex::Complex64;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+    const TAG: i32 = 10;
+
+    // ring shift with different types and lengths
+    let next = (rank + 1) % size;
+    let prev = (rank + size - 1) % size;
+    let x = vec![1000.0 + rank as f64; N];
+    let mut y = vec![0.0; N];
+    let status = comm.sendrecv(&x, next, TAG, &mut y, prev as i32, TAG)?;
+    assert_eq!(status.source, prev as i32);
+    assert_eq!(status.tag, TAG);
+    assert_eq!(status.count, Some(N));
+    assert_eq!(y, vec![1000.0 + prev as f64; N]);
+
+    let u = vec![rank as i32; 2 * N];
+    let mut v = vec![Complex64::new(0.0, 0.0); N];
+    let w = vec![Complex64::new(rank as f64, -(rank as f64)); N];
+    let mut z = vec![0_i32; 2 * N];
+    let status = comm.sendrecv(&w, next, TAG + 1, &mut v, -1, -1)?;
+    assert_eq!(status.tag, TAG + 1);
+    assert_eq!(v, vec![Complex64::new(prev as f64, -(prev as f64)); N]);
+    let status = comm.sendrecv(&u, prev, TAG + 2, &mut z, next as i32, TAG + 2)?;
+    assert_eq!(status.count, Some(2 * N));
+    assert_eq!(z, vec![next as i32; 2 * N]);
+
+    // ring shift using a single buffer
+    let mut data = vec![rank; N];
+    let status = comm.sendrecv_replace(&mut data, next, TAG + 3, prev as i32, TAG + 3)?;
+    assert_eq!(status.source, prev as i32);
+    assert_eq!(status.count, Some(N));
+    assert_eq!(data, vec![prev; N]);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
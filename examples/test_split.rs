@@ -0,0 +1,59 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    // 2D decomposition with NCOL columns (row-major numbering of the processes)
+    const NCOL: usize = 2;
+    assert!(size % NCOL == 0);
+    let nrow = size / NCOL;
+    let (i, j) = (rank / NCOL, rank % NCOL);
+
+    // row communicator
+    let mut row = comm.split(Some(i), j)?.unwrap();
+    assert_eq!(row.rank()?, j);
+    assert_eq!(row.size()?, NCOL);
+    let mut sum = [0];
+    row.allreduce(&mut sum, &[rank], MpiOpInt::Sum)?;
+    assert_eq!(sum[0], (0..NCOL).map(|c| i * NCOL + c).sum::<usize>());
+
+    // column communicator (with reversed order of ranks)
+    let mut col = comm.split(Some(j), nrow - 1 - i)?.unwrap();
+    assert_eq!(col.rank()?, nrow - 1 - i);
+    assert_eq!(col.size()?, nrow);
+    let mut sum = [0];
+    col.allreduce(&mut sum, &[rank], MpiOpInt::Sum)?;
+    assert_eq!(sum[0], (0..nrow).map(|r| r * NCOL + j).sum::<usize>());
+
+    // only the even ranks participate
+    let color = if rank % 2 == 0 { Some(0) } else { None };
+    let even = comm.split(color, rank)?;
+    match even {
+        Some(mut even) => {
+            assert!(rank % 2 == 0);
+            assert_eq!(even.rank()?, rank / 2);
+            assert_eq!(even.size()?, size.div_ceil(2));
+        }
+        None => assert!(rank % 2 != 0),
+    }
+
+    // processes sharing memory
+    let mut shared = comm.split_shared(rank)?;
+    let shared_size = shared.size()?;
+    assert!(shared.rank()? < shared_size);
+    assert!(shared_size <= size);
+    let mut count = [0];
+    shared.allreduce(&mut count, &[1], MpiOpInt::Sum)?;
+    assert_eq!(count[0], shared_size);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use msgpass::*;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const ROOT: usize = 0;
+    const TAG: i32 = 10;
+
+    // broadcast (multi-byte characters are kept)
+    let hello = "Processor 0 says hello 😊 (a long message that would not fit in a small buffer)";
+    let mut message = if rank == ROOT { hello.to_string() } else { "old".to_string() };
+    comm.broadcast_string(ROOT, &mut message)?;
+    assert_eq!(message, hello);
+
+    // broadcast an empty string
+    let mut empty = if rank == ROOT { String::new() } else { "old".to_string() };
+    comm.broadcast_string(ROOT, &mut empty)?;
+    assert_eq!(empty, "");
+
+    // send/receive (the length depends on the destination)
+    if rank == ROOT {
+        for to in 1..size {
+            comm.send_string(&"💖".repeat(to), to, TAG)?;
+        }
+    } else {
+        let (string, status) = comm.receive_string(ROOT as i32, TAG)?;
+        assert_eq!(string, "💖".repeat(rank));
+        assert_eq!(status.count, Some(4 * rank));
+
+        // invalid UTF-8
+        comm.send(&[240_u8, 159], ROOT, TAG + 1)?;
+    }
+    if rank == ROOT {
+        for from in 1..size {
+            assert_eq!(comm.receive_string(from as i32, TAG + 1).err(), Some("cannot convert bytes to UTF-8 string"));
+        }
+    }
+
+    // gather
+    let name = format!("rank #{} ", rank).repeat(rank);
+    let all = comm.gather_strings(ROOT, &name)?;
+    let correct: Vec<_> = (0..size).map(|r| format!("rank #{} ", r).repeat(r)).collect();
+    if rank == ROOT {
+        assert_eq!(all, Some(correct.clone()));
+    } else {
+        assert_eq!(all, None);
+    }
+
+    // allgather
+    let all = comm.allgather_strings(&name)?;
+    assert_eq!(all, correct);
+
+    mpi_finalize()?;
+
+    if rank == ROOT {
+        println!("... success ...");
+    }
+    Ok(())
+}
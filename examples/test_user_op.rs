@@ -0,0 +1,67 @@
+use msgpass::*;
+use num_complex::Complex64;
+
+fn main() -> Result<(), StrError> {
+    mpi_init()?;
+
+    let mut comm = Communicator::new()?;
+    let rank = comm.rank()?;
+    let size = comm.size()?;
+
+    const N: usize = 3;
+
+    // element-wise max-abs
+    let max_abs = UserOp::new(
+        |a: &[f64], b: &mut [f64]| {
+            for i in 0..a.len() {
+                b[i] = f64::max(a[i].abs(), b[i].abs());
+            }
+        },
+        true,
+    )?;
+    let sign = if rank % 2 == 0 { -1.0 } else { 1.0 };
+    let x = vec![sign * (1 + rank) as f64; N];
+    let mut y = vec![0.0; N];
+    comm.allreduce_with(&mut y, &x, &max_abs)?;
+    assert_eq!(y, vec![size as f64; N]);
+
+    // complex max-by-modulus
+    let max_modulus = UserOp::new(
+        |a: &[Complex64], b: &mut [Complex64]| {
+            for i in 0..a.len() {
+                if a[i].norm() > b[i].norm() {
+                    b[i] = a[i];
+                }
+            }
+        },
+        true,
+    )?;
+    let root = size - 1;
+    let x = vec![Complex64::new(0.0, rank as f64); N];
+    let mut y = vec![Complex64::new(0.0, 0.0); N];
+    comm.reduce_with(root, &mut y, &x, &max_modulus)?;
+    if rank == root {
+        assert_eq!(y, vec![Complex64::new(0.0, (size - 1) as f64); N]);
+    }
+
+    // non-commutative operation (keeps the value from the lowest rank)
+    let first = UserOp::new(|a: &[i32], b: &mut [i32]| b.copy_from_slice(a), false)?;
+    let x = vec![100 + rank as i32; N];
+    let mut y = vec![0_i32; N];
+    comm.scan_with(&mut y, &x, &first)?;
+    assert_eq!(y, vec![100; N]);
+    comm.allreduce_with(&mut y, &x, &first)?;
+    assert_eq!(y, vec![100; N]);
+
+    // the operations are freed when dropped
+    drop(max_abs);
+    drop(max_modulus);
+    drop(first);
+
+    mpi_finalize()?;
+
+    if rank == 0 {
+        println!("... success ...");
+    }
+    Ok(())
+}
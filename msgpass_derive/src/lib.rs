@@ -0,0 +1,168 @@
+//! Derive macro for the msgpass crate
+//!
+//! Use it via the `derive` feature of msgpass (see `msgpass::Equivalence`).
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Index, Member, Type};
+
+/// Implements `MpiData` for a struct by generating the corresponding MPI struct datatype
+///
+/// The struct must be `#[repr(C)]` and all fields must implement `MpiData` (or be arrays of such
+/// types). Thus, pointers and references are rejected. The struct must also implement `Copy`
+/// because `Copy` is a supertrait of `MpiData` (a missing `Copy` is reported as an unsatisfied bound of `MpiData`).
+///
+/// The datatype is created (and committed) the first time it is needed, thus after `mpi_init`.
+///
+/// # Panics
+///
+/// The generated `type_index` cannot return an error; thus, it panics if the struct is communicated
+/// before `mpi_init` or if MPI fails to create the datatype.
+#[proc_macro_derive(Equivalence)]
+pub fn derive_equivalence(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Generates the implementation of MpiData
+fn expand(input: &DeriveInput) -> Result<TokenStream2, syn::Error> {
+    let name = &input.ident;
+    if !has_repr_c(input)? {
+        return Err(syn::Error::new_spanned(name, "Equivalence requires #[repr(C)]"));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "Equivalence does not support generic structs"));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(name, "Equivalence can only be derived for structs")),
+    };
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(name, "Equivalence requires at least one field"));
+    }
+    let mut lengths = Vec::new();
+    let mut offsets = Vec::new();
+    let mut types = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+        let (base, length) = split_array(&field.ty)?;
+        lengths.push(length);
+        offsets.push(quote! { ::std::mem::offset_of!(#name, #member) });
+        types.push(quote_spanned! {base.span()=> &::msgpass::MpiDatatype::of::<#base>() });
+    }
+    Ok(quote! {
+        unsafe impl ::msgpass::MpiData for #name {
+            fn type_index() -> i32 {
+                static DATATYPE: ::std::sync::OnceLock<::msgpass::MpiDatatype> = ::std::sync::OnceLock::new();
+                DATATYPE
+                    .get_or_init(|| {
+                        ::msgpass::MpiDatatype::structure(&[#(#lengths),*], &[#(#offsets),*], &[#(#types),*])
+                            .and_then(|fields| fields.resized(0, ::std::mem::size_of::<#name>()))
+                            .and_then(|datatype| datatype.commit())
+                            .expect("MPI failed to create the datatype of the struct")
+                    })
+                    .type_index()
+            }
+        }
+    })
+}
+
+/// Returns true if the struct has the #[repr(C)] attribute
+fn has_repr_c(input: &DeriveInput) -> Result<bool, syn::Error> {
+    let mut found = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                found = true;
+            }
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input); // e.g., align(8)
+                content.parse::<TokenStream2>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(found)
+}
+
+/// Returns the base type of (possibly nested) arrays and the total number of items
+fn split_array(ty: &Type) -> Result<(&Type, TokenStream2), syn::Error> {
+    match ty {
+        Type::Array(array) => {
+            let (base, inner) = split_array(&array.elem)?;
+            let len = &array.len;
+            Ok((base, quote! { (#inner) * (#len) }))
+        }
+        Type::Paren(paren) => split_array(&paren.elem),
+        Type::Group(group) => split_array(&group.elem),
+        Type::Ptr(_) | Type::Reference(_) => Err(syn::Error::new_spanned(ty, "Equivalence does not support pointers or references")),
+        _ => Ok((ty, quote! { 1 })),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, split_array};
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput, Type};
+
+    #[test]
+    fn expand_captures_errors() {
+        let input: DeriveInput = parse_quote! { struct A { x: f64 } };
+        assert_eq!(expand(&input).err().unwrap().to_string(), "Equivalence requires #[repr(C)]");
+
+        let input: DeriveInput = parse_quote! { #[repr(C)] struct A<T> { x: T } };
+        assert_eq!(expand(&input).err().unwrap().to_string(), "Equivalence does not support generic structs");
+
+        let input: DeriveInput = parse_quote! { #[repr(C)] enum A { X, Y } };
+        assert_eq!(expand(&input).err().unwrap().to_string(), "Equivalence can only be derived for structs");
+
+        let input: DeriveInput = parse_quote! { #[repr(C)] struct A {} };
+        assert_eq!(expand(&input).err().unwrap().to_string(), "Equivalence requires at least one field");
+
+        let input: DeriveInput = parse_quote! { #[repr(C)] struct A { x: *const f64 } };
+        assert_eq!(expand(&input).err().unwrap().to_string(), "Equivalence does not support pointers or references");
+
+        let input: DeriveInput = parse_quote! { #[repr(C)] struct A<'a> { x: [&'a f64; 2] } };
+        assert_eq!(expand(&input).err().unwrap().to_string(), "Equivalence does not support generic structs");
+    }
+
+    #[test]
+    fn expand_works() {
+        let input: DeriveInput = parse_quote! { #[repr(C, align(8))] struct A { x: f64, y: [i32; 3] } };
+        let tokens = expand(&input).unwrap().to_string();
+        assert!(tokens.contains("unsafe impl :: msgpass :: MpiData for A"));
+        assert!(tokens.contains("offset_of ! (A , y)"));
+
+        let input: DeriveInput = parse_quote! { #[repr(C)] struct B(f64, u8); };
+        let tokens = expand(&input).unwrap().to_string();
+        assert!(tokens.contains("offset_of ! (B , 1)"));
+    }
+
+    #[test]
+    fn split_array_works() {
+        let ty: Type = parse_quote! { f64 };
+        let (base, length) = split_array(&ty).unwrap();
+        assert_eq!(quote! { #base }.to_string(), "f64");
+        assert_eq!(length.to_string(), "1");
+
+        let ty: Type = parse_quote! { [[i32; 3]; 2] };
+        let (base, length) = split_array(&ty).unwrap();
+        assert_eq!(quote! { #base }.to_string(), "i32");
+        assert_eq!(length.to_string(), "((1) * (3)) * (2)");
+
+        let ty: Type = parse_quote! { &'static f64 };
+        assert!(split_array(&ty).is_err());
+    }
+}
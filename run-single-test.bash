@@ -22,7 +22,7 @@ EXAMPLES="/tmp/msgpass/debug/examples"
 TEST=$1
 
 rm -rf $EXAMPLES
-cargo build --example $TEST
+cargo build --example $TEST --features derive,serde
 
 echo
 echo
@@ -15,10 +15,11 @@ export CARGO_TARGET_DIR="/tmp/msgpass"
 EXAMPLES="/tmp/msgpass/debug/examples"
 
 rm -rf $EXAMPLES
+# the optional features are always enabled to build all examples
 if [ "${FEATURE}" = "" ]; then
-    cargo build --examples
+    cargo build --examples --features derive,serde
 else
-    cargo build --examples --features $FEATURE
+    cargo build --examples --features $FEATURE,derive,serde
 fi
 
 for test in examples/test_*.rs; do
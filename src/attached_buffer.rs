@@ -0,0 +1,78 @@
+use crate::constants::*;
+use crate::StrError;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+extern "C" {
+    fn c_mpi_buffer_attach(buffer: *mut c_void, size: i32) -> i32;
+    fn c_mpi_buffer_detach() -> i32;
+}
+
+/// Indicates whether a buffer is attached or not (MPI allows only one buffer per process)
+static ATTACHED: AtomicBool = AtomicBool::new(false);
+
+/// Holds the buffer used by the buffered-mode send (see [crate::Communicator::bsend])
+///
+/// The buffer is attached to MPI on allocation and detached when dropped. Detaching
+/// waits until all messages in the buffer have been delivered.
+///
+/// Use [crate::Communicator::bsend_size] to compute the size required by each message.
+///
+/// **Note:** Only one buffer may be attached at a time (per process).
+pub struct AttachedBuffer {
+    buffer: Vec<u8>,
+    attached: bool,
+}
+
+impl Drop for AttachedBuffer {
+    /// Detaches the buffer (waiting for the buffered messages) and deallocates the memory
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+impl AttachedBuffer {
+    /// Allocates and attaches a new buffer
+    ///
+    /// `size` -- Size of the buffer in bytes
+    pub fn new(size: usize) -> Result<Self, StrError> {
+        let n = i32::try_from(size).map_err(|_| "the size of the buffer must not exceed i32::MAX")?;
+        if ATTACHED.swap(true, Ordering::SeqCst) {
+            return Err("another buffer is already attached");
+        }
+        let mut buffer = vec![0_u8; size];
+        unsafe {
+            let status = c_mpi_buffer_attach(buffer.as_mut_ptr() as *mut c_void, n);
+            if status != C_MPI_SUCCESS {
+                ATTACHED.store(false, Ordering::SeqCst);
+                return Err("MPI failed to attach the buffer");
+            }
+        }
+        Ok(AttachedBuffer { buffer, attached: true })
+    }
+
+    /// Returns the size of the buffer in bytes
+    pub fn size(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Detaches the buffer (waiting for the buffered messages)
+    pub fn detach(mut self) -> Result<(), StrError> {
+        let status = self.release();
+        if status != C_MPI_SUCCESS {
+            return Err("MPI failed to detach the buffer");
+        }
+        Ok(())
+    }
+
+    /// Detaches the buffer if still attached and returns the MPI status
+    fn release(&mut self) -> i32 {
+        if !self.attached {
+            return C_MPI_SUCCESS;
+        }
+        self.attached = false;
+        let status = unsafe { c_mpi_buffer_detach() };
+        ATTACHED.store(false, Ordering::SeqCst);
+        status
+    }
+}
@@ -1,2 +1,4 @@
 pub(crate) const C_MPI_SUCCESS: i32 = 0;
 pub(crate) const C_MPI_ERROR_INIT_THREADED: i32 = 10000;
+pub(crate) const C_MPI_ERROR_NAME_TOO_LONG: i32 = 10002;
+pub(crate) const C_MPI_ERROR_MESSAGE_SIZE: i32 = 10003;
@@ -7,7 +7,28 @@ pub(crate) fn to_i32(num: usize) -> i32 {
     i32::try_from(num).unwrap()
 }
 
+/// Converts an array of numbers to i32
+pub(crate) fn to_i32_vec(nums: &[usize]) -> Vec<i32> {
+    nums.iter().map(|&num| to_i32(num)).collect()
+}
+
+/// Computes the offsets of the parts with the given counts (packed in sequence)
+///
+/// Returns an array with `counts.len() + 1` entries; the last entry is the total count.
+pub(crate) fn counts_to_offsets(counts: &[usize]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(counts.len() + 1);
+    offsets.push(0);
+    let mut total = 0;
+    for count in counts {
+        total += count;
+        offsets.push(total);
+    }
+    offsets
+}
+
 /// Converts string to array of bytes (may truncate)
+///
+/// **Note:** See [crate::Communicator::broadcast_string] and [crate::Communicator::send_string] to communicate strings without truncation.
 pub fn str_to_bytes(dest: &mut [u8], src: &str) {
     if dest.len() == src.len() {
         dest.copy_from_slice(src.as_bytes());
@@ -43,6 +64,13 @@ mod tests {
         assert_eq!(m_i32, 2_i32);
     }
 
+    #[test]
+    fn counts_to_offsets_works() {
+        assert_eq!(to_i32_vec(&[1, 2, 3]), &[1, 2, 3]);
+        assert_eq!(counts_to_offsets(&[]), &[0]);
+        assert_eq!(counts_to_offsets(&[2, 0, 3]), &[0, 2, 2, 5]);
+    }
+
     #[test]
     fn str_to_bytes_works() {
         let mut buf10 = vec![0u8; 10];
@@ -0,0 +1,217 @@
+use crate::constants::*;
+use crate::conversion::{to_i32, to_i32_vec};
+use crate::enums::MpiOrder;
+use crate::mpi_data::MpiData;
+use crate::StrError;
+use std::sync::Mutex;
+
+extern "C" {
+    fn datatype_contiguous(count: i32, old_type_index: i32, type_index: *mut i32) -> i32;
+    fn datatype_vector(count: i32, block_length: i32, stride: i32, old_type_index: i32, type_index: *mut i32) -> i32;
+    fn datatype_hvector(count: i32, block_length: i32, stride: i64, old_type_index: i32, type_index: *mut i32) -> i32;
+    fn datatype_indexed(count: i32, block_lengths: *const i32, displacements: *const i32, old_type_index: i32, type_index: *mut i32) -> i32;
+    fn datatype_subarray(ndims: i32, sizes: *const i32, subsizes: *const i32, starts: *const i32, order_index: i32, old_type_index: i32, type_index: *mut i32) -> i32;
+    fn datatype_struct(count: i32, block_lengths: *const i32, displacements: *const i64, type_indices: *const i32, type_index: *mut i32) -> i32;
+    fn datatype_resized(old_type_index: i32, lb: i64, extent: i64, type_index: *mut i32) -> i32;
+    fn datatype_commit(type_index: i32) -> i32;
+    fn datatype_free(type_index: i32);
+    fn datatype_size(type_index: i32, size: *mut i64) -> i32;
+    fn datatype_extent(type_index: i32, lb: *mut i64, extent: *mut i64, true_lb: *mut i64, true_extent: *mut i64) -> i32;
+}
+
+/// Serializes the changes to the registry of derived datatypes (in the C code)
+static REGISTRY: Mutex<()> = Mutex::new(());
+
+/// Holds an MPI datatype (wrapping the C data)
+///
+/// Derived datatypes describe non-contiguous or heterogeneous memory layouts (e.g., matrix columns
+/// or sub-blocks), thus the data can be communicated without copying into temporary arrays.
+/// A derived datatype must be committed (see [MpiDatatype::commit]) before being used in
+/// communication, and is freed when dropped.
+///
+/// See [crate::Communicator::send_datatype], [crate::Communicator::receive_datatype], and
+/// [crate::Communicator::broadcast_datatype].
+pub struct MpiDatatype {
+    index: i32,
+    owned: bool,
+    committed: bool,
+}
+
+impl Drop for MpiDatatype {
+    /// Frees the derived datatype
+    fn drop(&mut self) {
+        if self.owned {
+            let _guard = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+            unsafe {
+                datatype_free(self.index);
+            }
+        }
+    }
+}
+
+impl MpiDatatype {
+    /// Returns the datatype corresponding to an MpiData type (e.g., f64)
+    pub fn of<T: MpiData>() -> Self {
+        MpiDatatype { index: T::type_index(), owned: false, committed: true }
+    }
+
+    /// Creates a new derived datatype with the index returned by the C code
+    fn register<F>(create: F) -> Result<Self, StrError>
+    where
+        F: FnOnce(*mut i32) -> i32,
+    {
+        let _guard = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let mut index: i32 = 0;
+        let status = create(&mut index);
+        if status != C_MPI_SUCCESS {
+            return Err("MPI failed to create the derived datatype");
+        }
+        Ok(MpiDatatype { index, owned: true, committed: false })
+    }
+
+    /// Creates a datatype replicating `old` into `count` contiguous locations
+    pub fn contiguous(count: usize, old: &MpiDatatype) -> Result<Self, StrError> {
+        MpiDatatype::register(|index| unsafe { datatype_contiguous(to_i32(count), old.index, index) })
+    }
+
+    /// Creates a datatype with `count` blocks of `block_length` items, spaced by `stride` items
+    ///
+    /// For instance, a column of a row-major matrix with `m` rows and `n` columns is given by
+    /// `vector(m, 1, n, &MpiDatatype::of::<f64>())`.
+    pub fn vector(count: usize, block_length: usize, stride: usize, old: &MpiDatatype) -> Result<Self, StrError> {
+        MpiDatatype::register(|index| unsafe { datatype_vector(to_i32(count), to_i32(block_length), to_i32(stride), old.index, index) })
+    }
+
+    /// Creates a datatype with `count` blocks of `block_length` items, spaced by `stride` bytes
+    pub fn hvector(count: usize, block_length: usize, stride: isize, old: &MpiDatatype) -> Result<Self, StrError> {
+        MpiDatatype::register(|index| unsafe { datatype_hvector(to_i32(count), to_i32(block_length), stride as i64, old.index, index) })
+    }
+
+    /// Creates a datatype with blocks of varying lengths and positions
+    ///
+    /// `block_lengths` -- Number of items in each block
+    /// `displacements` -- Position of each block (in number of items)
+    pub fn indexed(block_lengths: &[usize], displacements: &[usize], old: &MpiDatatype) -> Result<Self, StrError> {
+        if block_lengths.len() != displacements.len() {
+            return Err("block_lengths.len() must equal displacements.len()");
+        }
+        let (b, d) = (to_i32_vec(block_lengths), to_i32_vec(displacements));
+        MpiDatatype::register(|index| unsafe { datatype_indexed(to_i32(b.len()), b.as_ptr(), d.as_ptr(), old.index, index) })
+    }
+
+    /// Creates a datatype describing a sub-block of a multidimensional array
+    ///
+    /// `sizes` -- Number of items in each dimension of the full array
+    /// `subsizes` -- Number of items in each dimension of the sub-block
+    /// `starts` -- Starting position of the sub-block in each dimension
+    /// `order` -- Storage order of the array
+    pub fn subarray(sizes: &[usize], subsizes: &[usize], starts: &[usize], order: MpiOrder, old: &MpiDatatype) -> Result<Self, StrError> {
+        if subsizes.len() != sizes.len() || starts.len() != sizes.len() {
+            return Err("sizes, subsizes, and starts must have the same length");
+        }
+        if (0..sizes.len()).any(|i| starts[i] + subsizes[i] > sizes[i]) {
+            return Err("the sub-block must fit within the array");
+        }
+        let (n, s, p) = (to_i32_vec(sizes), to_i32_vec(subsizes), to_i32_vec(starts));
+        MpiDatatype::register(|index| unsafe { datatype_subarray(to_i32(n.len()), n.as_ptr(), s.as_ptr(), p.as_ptr(), order.n(), old.index, index) })
+    }
+
+    /// Creates a datatype describing a structure with fields of (possibly) different datatypes
+    ///
+    /// `block_lengths` -- Number of items in each field
+    /// `displacements` -- Position of each field (in bytes; e.g., given by `std::mem::offset_of!`)
+    /// `types` -- Datatype of each field
+    ///
+    /// **Note:** Use [MpiDatatype::resized] to set the extent to the size of the Rust struct
+    /// (including the trailing padding) if arrays of structs are communicated.
+    pub fn structure(block_lengths: &[usize], displacements: &[usize], types: &[&MpiDatatype]) -> Result<Self, StrError> {
+        if block_lengths.len() != displacements.len() || types.len() != displacements.len() {
+            return Err("block_lengths, displacements, and types must have the same length");
+        }
+        let b = to_i32_vec(block_lengths);
+        let d: Vec<i64> = displacements.iter().map(|&x| x as i64).collect();
+        let t: Vec<i32> = types.iter().map(|x| x.index).collect();
+        MpiDatatype::register(|index| unsafe { datatype_struct(to_i32(b.len()), b.as_ptr(), d.as_ptr(), t.as_ptr(), index) })
+    }
+
+    /// Creates a copy of this datatype with a new lower bound and extent (in bytes)
+    pub fn resized(&self, lb: isize, extent: usize) -> Result<Self, StrError> {
+        MpiDatatype::register(|index| unsafe { datatype_resized(self.index, lb as i64, extent as i64, index) })
+    }
+
+    /// Commits the datatype (required before communication)
+    pub fn commit(mut self) -> Result<Self, StrError> {
+        if !self.committed {
+            let _guard = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+            unsafe {
+                let status = datatype_commit(self.index);
+                if status != C_MPI_SUCCESS {
+                    return Err("MPI failed to commit the derived datatype");
+                }
+            }
+            self.committed = true;
+        }
+        Ok(self)
+    }
+
+    /// Returns true if the datatype has been committed
+    pub fn is_committed(&self) -> bool {
+        self.committed
+    }
+
+    /// Returns the number of bytes of data in the datatype (excluding gaps)
+    pub fn size(&self) -> Result<usize, StrError> {
+        let mut size: i64 = 0;
+        unsafe {
+            let status = datatype_size(self.index, &mut size);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to get the size of the datatype");
+            }
+        }
+        Ok(size as usize)
+    }
+
+    /// Returns the lower bound and the extent of the datatype (in bytes)
+    ///
+    /// The extent is the distance between consecutive items when communicating many items.
+    pub fn extent(&self) -> Result<(isize, usize), StrError> {
+        let (lb, extent, _, _) = self.extents()?;
+        Ok((lb as isize, extent as usize))
+    }
+
+    /// Returns the index of the datatype (used by the C code)
+    #[doc(hidden)]
+    pub fn type_index(&self) -> i32 {
+        self.index
+    }
+
+    /// Returns `(lb, extent, true_lb, true_extent)`
+    fn extents(&self) -> Result<(i64, i64, i64, i64), StrError> {
+        let (mut lb, mut extent, mut true_lb, mut true_extent) = (0, 0, 0, 0);
+        unsafe {
+            let status = datatype_extent(self.index, &mut lb, &mut extent, &mut true_lb, &mut true_extent);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to get the extent of the datatype");
+            }
+        }
+        Ok((lb, extent, true_lb, true_extent))
+    }
+
+    /// Checks that `count` items of this datatype fit within a buffer with `bytes` bytes
+    pub(crate) fn check_buffer(&self, count: usize, bytes: usize) -> Result<(), StrError> {
+        if !self.committed {
+            return Err("the datatype must be committed");
+        }
+        if count == 0 {
+            return Ok(());
+        }
+        let (_, extent, true_lb, true_extent) = self.extents()?;
+        let last = (count - 1) as i64 * extent;
+        let start = true_lb + i64::min(0, last);
+        let end = true_lb + i64::max(0, last) + true_extent;
+        if start < 0 || end > bytes as i64 {
+            return Err("the buffer is too small for the datatype and count");
+        }
+        Ok(())
+    }
+}
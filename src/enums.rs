@@ -34,6 +34,44 @@ pub enum MpiThread {
     Multiple = 3,
 }
 
+/// Specifies the storage order of multidimensional arrays (see [crate::MpiDatatype::subarray])
+#[derive(Clone, Copy)]
+pub enum MpiOrder {
+    /// Row-major order (C order); the last index varies fastest
+    RowMajor = 0,
+
+    /// Column-major order (Fortran order); the first index varies fastest
+    ColMajor = 1,
+}
+
+/// Specifies the result of comparing communicators or groups (see [crate::Communicator::compare] and [crate::Group::compare])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MpiComparison {
+    /// Same object
+    Identical = 0,
+
+    /// Same members in the same order, but different contexts (e.g., duplicates)
+    Congruent = 1,
+
+    /// Same members in a different order
+    Similar = 2,
+
+    /// Different members
+    Unequal = 3,
+}
+
+impl MpiComparison {
+    /// Returns the comparison corresponding to the index returned by the C code
+    pub(crate) fn from_index(index: i32) -> Self {
+        match index {
+            0 => MpiComparison::Identical,
+            1 => MpiComparison::Congruent,
+            2 => MpiComparison::Similar,
+            _ => MpiComparison::Unequal,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum MpiType {
     I32 = 0,
@@ -42,9 +80,17 @@ pub(crate) enum MpiType {
     U64 = 3,
     F32 = 4,
     F64 = 5,
-    C32 = 6, // Complex32
-    C64 = 7, // Complex64
-    BYT = 8, // u8 (Byte)
+    C32 = 6,      // Complex32
+    C64 = 7,      // Complex64
+    BYT = 8,      // u8 (Byte)
+    F32Int = 9,   // (f32, i32) pair
+    F64Int = 10,  // (f64, i32) pair
+    I32Int = 11,  // (i32, i32) pair
+    LongInt = 12, // (c_long, i32) pair
+    I8 = 13,
+    I16 = 14,
+    U16 = 15,
+    Bool = 16,
 }
 
 /// Specifies the MPI operator used in reduce-like functions (for integer arrays)
@@ -78,9 +124,40 @@ pub enum MpiOpComplex {
 /// Specifies the MPI operator used in reduce-like functions (for byte arrays)
 #[derive(Clone, Copy)]
 pub enum MpiOpByte {
-    And = 7, // bitwise and
-    Or = 8,  // bitwise or
-    Xor = 9, // bitwise xor
+    Max = 0,  // maximum
+    Min = 1,  // minimum
+    Sum = 2,  // sum
+    Prod = 3, // product
+    And = 7,  // bitwise and
+    Or = 8,   // bitwise or
+    Xor = 9,  // bitwise xor
+}
+
+/// Specifies the MPI operator used in reduce-like functions (for boolean arrays)
+#[derive(Clone, Copy)]
+pub enum MpiOpBool {
+    And = 4, // logical and
+    Or = 5,  // logical or
+    Xor = 6, // logical xor
+}
+
+/// Specifies the MPI operator used in reduce-like functions (for (value, index) pairs)
+///
+/// The operators find the extreme value and the index (e.g., rank) associated with it.
+/// If the extreme value is not unique, the lowest index is selected.
+#[derive(Clone, Copy)]
+pub enum MpiOpLoc {
+    Max = 10, // maximum and location
+    Min = 11, // minimum and location
+}
+
+/// Defines the MPI operator enums used in reduce-like functions
+///
+/// See [MpiOpInt], [MpiOpReal], [MpiOpComplex], [MpiOpByte], [MpiOpBool], and [MpiOpLoc]
+pub trait MpiOp: Copy {
+    /// Returns the index of the operator (used by the C code)
+    #[doc(hidden)]
+    fn op_index(&self) -> i32;
 }
 
 impl MpiThread {
@@ -89,6 +166,12 @@ impl MpiThread {
     }
 }
 
+impl MpiOrder {
+    pub(crate) fn n(&self) -> i32 {
+        *self as i32
+    }
+}
+
 impl MpiType {
     pub(crate) fn n(&self) -> i32 {
         *self as i32
@@ -118,3 +201,51 @@ impl MpiOpByte {
         *self as i32
     }
 }
+
+impl MpiOpBool {
+    pub(crate) fn n(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl MpiOpLoc {
+    pub(crate) fn n(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl MpiOp for MpiOpInt {
+    fn op_index(&self) -> i32 {
+        self.n()
+    }
+}
+
+impl MpiOp for MpiOpReal {
+    fn op_index(&self) -> i32 {
+        self.n()
+    }
+}
+
+impl MpiOp for MpiOpComplex {
+    fn op_index(&self) -> i32 {
+        self.n()
+    }
+}
+
+impl MpiOp for MpiOpByte {
+    fn op_index(&self) -> i32 {
+        self.n()
+    }
+}
+
+impl MpiOp for MpiOpBool {
+    fn op_index(&self) -> i32 {
+        self.n()
+    }
+}
+
+impl MpiOp for MpiOpLoc {
+    fn op_index(&self) -> i32 {
+        self.n()
+    }
+}
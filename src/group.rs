@@ -0,0 +1,166 @@
+use crate::constants::*;
+use crate::conversion::{to_i32, to_i32_vec};
+use crate::enums::MpiComparison;
+use crate::StrError;
+
+#[repr(C)]
+pub(crate) struct ExtGroup {
+    data: [u8; 0],
+    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+extern "C" {
+    fn group_drop(group: *mut ExtGroup);
+    fn group_size(group: *mut ExtGroup, size: *mut i32) -> i32;
+    fn group_rank(group: *mut ExtGroup, rank: *mut i32) -> i32;
+    fn group_union(group: *mut ExtGroup, other: *mut ExtGroup, new_group: *mut *mut ExtGroup) -> i32;
+    fn group_intersection(group: *mut ExtGroup, other: *mut ExtGroup, new_group: *mut *mut ExtGroup) -> i32;
+    fn group_difference(group: *mut ExtGroup, other: *mut ExtGroup, new_group: *mut *mut ExtGroup) -> i32;
+    fn group_include(group: *mut ExtGroup, n: i32, ranks: *const i32, new_group: *mut *mut ExtGroup) -> i32;
+    fn group_exclude(group: *mut ExtGroup, n: i32, ranks: *const i32, new_group: *mut *mut ExtGroup) -> i32;
+    fn group_range_include(group: *mut ExtGroup, n: i32, ranges: *const i32, new_group: *mut *mut ExtGroup) -> i32;
+    fn group_translate_ranks(group: *mut ExtGroup, n: i32, ranks: *const i32, other: *mut ExtGroup, other_ranks: *mut i32) -> i32;
+    fn group_compare(group: *mut ExtGroup, other: *mut ExtGroup, result_index: *mut i32) -> i32;
+}
+
+/// Holds a group of processes (wrapping the C data)
+///
+/// A group is an ordered set of processes; the rank of a process is its position in the group.
+/// Groups are obtained from communicators (see [crate::Communicator::group]) and combined with
+/// the set operations below; then, a communicator can be created from a group (see
+/// [crate::Communicator::from_group]). Group operations are local (no communication happens).
+///
+/// The group is freed when dropped.
+pub struct Group {
+    handle: *mut ExtGroup,
+}
+
+impl Drop for Group {
+    /// Frees the group and deallocates the C memory
+    fn drop(&mut self) {
+        unsafe {
+            group_drop(self.handle);
+        }
+    }
+}
+
+impl Group {
+    /// Creates a new instance with the group allocated by the C code
+    pub(crate) fn register<F>(create: F) -> Result<Self, StrError>
+    where
+        F: FnOnce(*mut *mut ExtGroup) -> i32,
+    {
+        let mut ext_group: *mut ExtGroup = std::ptr::null_mut();
+        let status = create(&mut ext_group);
+        if status != C_MPI_SUCCESS || ext_group.is_null() {
+            return Err("MPI failed to create the group");
+        }
+        Ok(Group { handle: ext_group })
+    }
+
+    /// Returns the C group
+    pub(crate) fn ext(&self) -> *mut ExtGroup {
+        self.handle
+    }
+
+    /// Returns the number of processes in the group
+    pub fn size(&self) -> Result<usize, StrError> {
+        let mut size: i32 = 0;
+        unsafe {
+            let status = group_size(self.handle, &mut size);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to get the size of the group");
+            }
+        }
+        Ok(size as usize)
+    }
+
+    /// Returns the rank of the calling process in the group (None if the process is not a member)
+    pub fn rank(&self) -> Result<Option<usize>, StrError> {
+        let mut rank: i32 = 0;
+        unsafe {
+            let status = group_rank(self.handle, &mut rank);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to get the rank in the group");
+            }
+        }
+        Ok(if rank < 0 { None } else { Some(rank as usize) })
+    }
+
+    /// Returns a group with the processes of this group followed by the other processes of `other`
+    pub fn union(&self, other: &Group) -> Result<Group, StrError> {
+        Group::register(|new_group| unsafe { group_union(self.handle, other.handle, new_group) })
+    }
+
+    /// Returns a group with the processes of this group that are also in `other` (ordered as in this group)
+    pub fn intersection(&self, other: &Group) -> Result<Group, StrError> {
+        Group::register(|new_group| unsafe { group_intersection(self.handle, other.handle, new_group) })
+    }
+
+    /// Returns a group with the processes of this group that are not in `other` (ordered as in this group)
+    pub fn difference(&self, other: &Group) -> Result<Group, StrError> {
+        Group::register(|new_group| unsafe { group_difference(self.handle, other.handle, new_group) })
+    }
+
+    /// Returns a group with the listed processes only (in the order of `ranks`)
+    ///
+    /// `ranks` -- Ranks in this group of the members of the new group (must be distinct)
+    pub fn include(&self, ranks: &[usize]) -> Result<Group, StrError> {
+        let r = to_i32_vec(ranks);
+        Group::register(|new_group| unsafe { group_include(self.handle, to_i32(r.len()), r.as_ptr(), new_group) })
+    }
+
+    /// Returns a group without the listed processes (ordered as in this group)
+    ///
+    /// `ranks` -- Ranks in this group of the processes to be excluded (must be distinct)
+    pub fn exclude(&self, ranks: &[usize]) -> Result<Group, StrError> {
+        let r = to_i32_vec(ranks);
+        Group::register(|new_group| unsafe { group_exclude(self.handle, to_i32(r.len()), r.as_ptr(), new_group) })
+    }
+
+    /// Returns a group with the processes given by ranges of ranks
+    ///
+    /// `ranges` -- The `(first, last, stride)` triplets, where `last` is included; e.g., `(0, 6, 2)` gives 0, 2, 4, 6.
+    ///             The stride must not be zero and may be negative if `first > last`.
+    pub fn range_include(&self, ranges: &[(usize, usize, i32)]) -> Result<Group, StrError> {
+        if ranges.iter().any(|r| r.2 == 0) {
+            return Err("the stride of the ranges must not be zero");
+        }
+        let r: Vec<i32> = ranges.iter().flat_map(|&(first, last, stride)| [to_i32(first), to_i32(last), stride]).collect();
+        Group::register(|new_group| unsafe { group_range_include(self.handle, to_i32(ranges.len()), r.as_ptr(), new_group) })
+    }
+
+    /// Translates ranks in this group to ranks in another group
+    ///
+    /// For instance, a rank in a subset communicator is mapped to the world rank using the group of
+    /// the subset communicator as `self` and the group of the world communicator as `other`.
+    ///
+    /// Returns the ranks in `other` (None for the processes that are not members of `other`).
+    pub fn translate_ranks(&self, ranks: &[usize], other: &Group) -> Result<Vec<Option<usize>>, StrError> {
+        let r = to_i32_vec(ranks);
+        let mut other_ranks = vec![0_i32; r.len()];
+        unsafe {
+            let status = group_translate_ranks(self.handle, to_i32(r.len()), r.as_ptr(), other.handle, other_ranks.as_mut_ptr());
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to translate the ranks");
+            }
+        }
+        Ok(other_ranks.iter().map(|&r| if r < 0 { None } else { Some(r as usize) }).collect())
+    }
+
+    /// Compares this group with another one
+    ///
+    /// Returns [MpiComparison::Identical] if both have the same members in the same order,
+    /// [MpiComparison::Similar] if they have the same members in a different order, and
+    /// [MpiComparison::Unequal] otherwise.
+    pub fn compare(&self, other: &Group) -> Result<MpiComparison, StrError> {
+        let mut index: i32 = 0;
+        unsafe {
+            let status = group_compare(self.handle, other.handle, &mut index);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to compare the groups");
+            }
+        }
+        Ok(MpiComparison::from_index(index))
+    }
+}
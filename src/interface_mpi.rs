@@ -1,10 +1,19 @@
 use crate::constants::*;
-use crate::conversion::to_i32;
+use crate::conversion::{bytes_to_string_lossy, counts_to_offsets, to_i32, to_i32_vec};
+use crate::datatype::MpiDatatype;
 use crate::enums::*;
+use crate::group::{ExtGroup, Group};
+use crate::mpi_data::*;
+use crate::request::{ExtRequest, PersistentRequest, Request};
+use crate::status::{ExtStatus, Status};
+use crate::user_op::{ExtUserOp, UserOp};
 use crate::StrError;
 use num_complex::{Complex32, Complex64};
 use std::ffi::c_void;
 
+/// Allocates the buffer for the n elements of a matched message (called by the C code)
+type AllocateFunction = extern "C" fn(context: *mut c_void, n: i32) -> *mut c_void;
+
 #[repr(C)]
 struct ExtCommunicator {
     data: [u8; 0],
@@ -20,7 +29,19 @@ extern "C" {
     fn c_mpi_world_size(size: *mut i32) -> i32;
     fn comm_drop(comm: *mut ExtCommunicator);
     fn comm_new() -> *mut ExtCommunicator;
-    fn comm_new_subset(n_rank: i32, ranks: *const i32) -> *mut ExtCommunicator;
+    fn comm_new_subset(n_rank: i32, ranks: *const i32, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_new_subset_group(n_rank: i32, ranks: *const i32, tag: i32, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_split(comm: *mut ExtCommunicator, color: i32, key: i32, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_split_shared(comm: *mut ExtCommunicator, key: i32, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_duplicate(comm: *mut ExtCommunicator, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_idup(comm: *mut ExtCommunicator, req: *mut ExtRequest, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_idup_complete(comm: *mut ExtCommunicator) -> i32;
+    fn comm_compare(comm: *mut ExtCommunicator, other: *mut ExtCommunicator, result_index: *mut i32) -> i32;
+    fn comm_set_name(comm: *mut ExtCommunicator, name: *const std::ffi::c_char) -> i32;
+    fn comm_get_name(comm: *mut ExtCommunicator, max_len: i32, name: *mut u8, len: *mut i32) -> i32;
+    fn comm_group(comm: *mut ExtCommunicator, new_group: *mut *mut ExtGroup) -> i32;
+    fn comm_from_group(comm: *mut ExtCommunicator, group: *mut ExtGroup, new_comm: *mut *mut ExtCommunicator) -> i32;
+    fn comm_from_group_members(comm: *mut ExtCommunicator, group: *mut ExtGroup, tag: i32, new_comm: *mut *mut ExtCommunicator) -> i32;
     fn comm_abort(comm: *mut ExtCommunicator, error_code: i32) -> i32;
     fn comm_barrier(comm: *mut ExtCommunicator) -> i32;
     fn comm_rank(comm: *mut ExtCommunicator, rank: *mut i32) -> i32;
@@ -28,14 +49,45 @@ extern "C" {
     fn comm_broadcast(comm: *mut ExtCommunicator, sender: i32, n: i32, x: *mut c_void, type_index: i32) -> i32;
     fn comm_reduce(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op_index: i32) -> i32;
     fn comm_allreduce(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_reduce_in_place_im_root(comm: *mut ExtCommunicator, root: i32, n: i32, data: *mut c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_reduce_in_place_im_not_root(comm: *mut ExtCommunicator, root: i32, n: i32, data: *const c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_allreduce_in_place(comm: *mut ExtCommunicator, n: i32, data: *mut c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_scan(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_exscan(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_reduce_user_op(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op: *mut ExtUserOp, context: *mut c_void) -> i32;
+    fn comm_allreduce_user_op(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op: *mut ExtUserOp, context: *mut c_void) -> i32;
+    fn comm_scan_user_op(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op: *mut ExtUserOp, context: *mut c_void) -> i32;
+    fn comm_reduce_scatter_block(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32, op_index: i32) -> i32;
+    fn comm_reduce_scatter(comm: *mut ExtCommunicator, dest: *mut c_void, orig: *const c_void, counts: *const i32, type_index: i32, op_index: i32) -> i32;
     fn comm_send(comm: *mut ExtCommunicator, n: i32, data: *const c_void, type_index: i32, to_rank: i32, tag: i32) -> i32;
-    fn comm_receive(comm: *mut ExtCommunicator, n: i32, data: *mut c_void, type_index: i32, from_rank: i32, tag: i32) -> i32;
-    fn comm_get_receive_status(comm: *mut ExtCommunicator, source: *mut i32, tag: *mut i32, error: *mut i32);
+    fn comm_ssend(comm: *mut ExtCommunicator, n: i32, data: *const c_void, type_index: i32, to_rank: i32, tag: i32) -> i32;
+    fn comm_bsend(comm: *mut ExtCommunicator, n: i32, data: *const c_void, type_index: i32, to_rank: i32, tag: i32) -> i32;
+    fn comm_rsend(comm: *mut ExtCommunicator, n: i32, data: *const c_void, type_index: i32, to_rank: i32, tag: i32) -> i32;
+    fn comm_bsend_size(comm: *mut ExtCommunicator, n: i32, type_index: i32, size: *mut i32) -> i32;
+    fn comm_receive(comm: *mut ExtCommunicator, n: i32, data: *mut c_void, type_index: i32, from_rank: i32, tag: i32, status: *mut ExtStatus) -> i32;
+    fn comm_sendrecv(comm: *mut ExtCommunicator, n_send: i32, send_data: *const c_void, send_type_index: i32, to_rank: i32, send_tag: i32, n_recv: i32, recv_data: *mut c_void, recv_type_index: i32, from_rank: i32, recv_tag: i32, status: *mut ExtStatus) -> i32;
+    fn comm_sendrecv_replace(comm: *mut ExtCommunicator, n: i32, data: *mut c_void, type_index: i32, to_rank: i32, send_tag: i32, from_rank: i32, recv_tag: i32, status: *mut ExtStatus) -> i32;
+    fn comm_isend(comm: *mut ExtCommunicator, req: *mut ExtRequest, n: i32, data: *const c_void, type_index: i32, to_rank: i32, tag: i32) -> i32;
+    fn comm_irecv(comm: *mut ExtCommunicator, req: *mut ExtRequest, n: i32, data: *mut c_void, type_index: i32, from_rank: i32, tag: i32) -> i32;
+    fn comm_send_init(comm: *mut ExtCommunicator, req: *mut ExtRequest, n: i32, data: *const c_void, type_index: i32, to_rank: i32, tag: i32) -> i32;
+    fn comm_recv_init(comm: *mut ExtCommunicator, req: *mut ExtRequest, n: i32, data: *mut c_void, type_index: i32, from_rank: i32, tag: i32) -> i32;
+    fn comm_probe(comm: *mut ExtCommunicator, from_rank: i32, tag: i32, type_index: i32, status: *mut ExtStatus) -> i32;
+    fn comm_mprobe_receive(comm: *mut ExtCommunicator, from_rank: i32, tag: i32, type_index: i32, allocate: AllocateFunction, context: *mut c_void, status: *mut ExtStatus) -> i32;
+    fn comm_iprobe(comm: *mut ExtCommunicator, from_rank: i32, tag: i32, type_index: i32, flag: *mut i32, status: *mut ExtStatus) -> i32;
     fn comm_gather_im_root(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32) -> i32;
     fn comm_gather_im_not_root(comm: *mut ExtCommunicator, root: i32, n: i32, orig: *const c_void, type_index: i32) -> i32;
     fn comm_allgather(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32) -> i32;
+    fn comm_gather_in_place_im_root(comm: *mut ExtCommunicator, root: i32, n: i32, data: *mut c_void, type_index: i32) -> i32;
+    fn comm_allgather_in_place(comm: *mut ExtCommunicator, n: i32, data: *mut c_void, type_index: i32) -> i32;
     fn comm_scatter_im_root(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32) -> i32;
     fn comm_scatter_im_not_root(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, type_index: i32) -> i32;
+    fn comm_gatherv_im_root(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, counts: *const i32, displs: *const i32, orig: *const c_void, type_index: i32) -> i32;
+    fn comm_gatherv_im_not_root(comm: *mut ExtCommunicator, root: i32, n: i32, orig: *const c_void, type_index: i32) -> i32;
+    fn comm_allgatherv(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, counts: *const i32, displs: *const i32, orig: *const c_void, type_index: i32) -> i32;
+    fn comm_scatterv_im_root(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, counts: *const i32, displs: *const i32, orig: *const c_void, type_index: i32) -> i32;
+    fn comm_scatterv_im_not_root(comm: *mut ExtCommunicator, root: i32, n: i32, dest: *mut c_void, type_index: i32) -> i32;
+    fn comm_alltoall(comm: *mut ExtCommunicator, n: i32, dest: *mut c_void, orig: *const c_void, type_index: i32) -> i32;
+    fn comm_alltoallv(comm: *mut ExtCommunicator, dest: *mut c_void, recv_counts: *const i32, recv_displs: *const i32, orig: *const c_void, send_counts: *const i32, send_displs: *const i32, type_index: i32) -> i32;
 }
 
 /// Initializes the MPI execution environment
@@ -114,14 +166,19 @@ pub fn mpi_world_size() -> Result<usize, StrError> {
 
 /// Implements the Rust communicator (wrapping the C data)
 ///
-/// This struct holds a pointer to the C communicator, which stores the communicator (MPI_Comm),
-/// the group (MPI_Group), and the status from recv calls (MPI_Status).
+/// This struct holds a pointer to the C communicator, which stores the communicator (MPI_Comm)
+/// and the group (MPI_Group).
+///
+/// The communicator (except the world communicator) and the group are freed when dropped.
+/// Since freeing a communicator is a collective operation, all processes of the communicator
+/// should drop it (in the same order). Dropping after `mpi_finalize` is allowed (nothing is freed
+/// by MPI in this case because MPI_Finalize has already done it).
 pub struct Communicator {
     handle: *mut ExtCommunicator,
 }
 
 impl Drop for Communicator {
-    /// Deallocates the C memory
+    /// Frees the communicator and the group and deallocates the C memory
     fn drop(&mut self) {
         unsafe {
             comm_drop(self.handle);
@@ -129,6 +186,46 @@ impl Drop for Communicator {
     }
 }
 
+impl Clone for Communicator {
+    /// Duplicates the communicator (see [Communicator::duplicate])
+    ///
+    /// **Warning:** Panics if MPI fails to duplicate the communicator.
+    fn clone(&self) -> Self {
+        self.duplicate().expect("MPI failed to duplicate the communicator")
+    }
+}
+
+/// Holds a communicator being duplicated by a non-blocking operation (see [Communicator::idup])
+///
+/// The operation is waited for (and the new communicator is freed) when dropped.
+#[must_use = "the duplication is waited for when dropped; call wait() or test() instead"]
+pub struct PendingCommunicator {
+    request: Request<'static>, // dropped (thus waited for) before the communicator
+    comm: Communicator,
+}
+
+impl PendingCommunicator {
+    /// Waits for the duplication to complete and returns the new communicator
+    pub fn wait(self) -> Result<Communicator, StrError> {
+        let PendingCommunicator { request, comm } = self;
+        request.wait()?;
+        unsafe {
+            let status = comm_idup_complete(comm.handle);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to get the group of the duplicated communicator");
+            }
+        }
+        Ok(comm)
+    }
+
+    /// Tests whether the duplication has completed or not
+    ///
+    /// Returns true if the duplication has completed (then, [PendingCommunicator::wait] returns immediately).
+    pub fn test(&mut self) -> Result<bool, StrError> {
+        Ok(self.request.test()?.is_some())
+    }
+}
+
 impl Communicator {
     /// Allocates a new instance
     pub fn new() -> Result<Self, StrError> {
@@ -142,16 +239,222 @@ impl Communicator {
     }
 
     /// Allocates a new instance using a subset of processors
-    pub fn new_subset(ranks: &[usize]) -> Result<Self, StrError> {
+    ///
+    /// This is a collective operation: all processes (of the world communicator) must call it.
+    ///
+    /// `ranks` -- The ranks (in the world communicator) of the members of the subset (in the order of the new ranks)
+    ///
+    /// Returns None on the processes that are not members of the subset.
+    pub fn new_subset(ranks: &[usize]) -> Result<Option<Self>, StrError> {
+        let c_ranks = to_i32_vec(ranks);
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
         unsafe {
-            let n = to_i32(ranks.len());
-            let c_ranks: Vec<i32> = ranks.iter().map(|r| *r as i32).collect();
-            let ext_comm = comm_new_subset(n, c_ranks.as_ptr());
-            if ext_comm.is_null() {
+            let status = comm_new_subset(to_i32(c_ranks.len()), c_ranks.as_ptr(), &mut ext_comm);
+            if status != C_MPI_SUCCESS {
                 return Err("MPI failed to create subset communicator");
             }
-            Ok(Communicator { handle: ext_comm })
         }
+        if ext_comm.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Communicator { handle: ext_comm }))
+    }
+
+    /// Allocates a new instance using a subset of processors (only the members call it)
+    ///
+    /// Unlike [Communicator::new_subset], only the members of the subset participate;
+    /// thus, the other processes may do something else in the meantime.
+    ///
+    /// `ranks` -- The ranks (in the world communicator) of the members of the subset (in the order of the new ranks)
+    /// `tag` -- Distinguishes concurrent calls with overlapping subsets (must be non-negative)
+    pub fn new_subset_group(ranks: &[usize], tag: i32) -> Result<Self, StrError> {
+        if !ranks.contains(&mpi_world_rank()?) {
+            return Err("this process is not a member of the subset");
+        }
+        if tag < 0 {
+            return Err("the tag must be non-negative");
+        }
+        let c_ranks = to_i32_vec(ranks);
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_new_subset_group(to_i32(c_ranks.len()), c_ranks.as_ptr(), tag, &mut ext_comm);
+            if status != C_MPI_SUCCESS || ext_comm.is_null() {
+                return Err("MPI failed to create subset communicator (group)");
+            }
+        }
+        Ok(Communicator { handle: ext_comm })
+    }
+
+    /// Splits the processes of this communicator into disjoint sub-communicators
+    ///
+    /// This is a collective operation: all processes of this communicator must call it.
+    ///
+    /// `color` -- Processes with the same color are placed in the same sub-communicator
+    ///            (None means that this process does not participate; i.e., MPI_UNDEFINED)
+    /// `key` -- Determines the order of the ranks in the sub-communicator (ties are broken by the rank in this communicator)
+    ///
+    /// Returns the sub-communicator of this process or None if `color` is None.
+    ///
+    /// For instance, the row communicators of a 2D decomposition with `ncol` columns are given by
+    /// `split(Some(rank / ncol), rank % ncol)`.
+    pub fn split(&self, color: Option<usize>, key: usize) -> Result<Option<Communicator>, StrError> {
+        let c = match color {
+            Some(c) => to_i32(c),
+            None => -1,
+        };
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_split(self.handle, c, to_i32(key), &mut ext_comm);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to split the communicator");
+            }
+        }
+        if ext_comm.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Communicator { handle: ext_comm }))
+    }
+
+    /// Splits the processes of this communicator into sub-communicators of processes sharing memory (e.g., on the same node)
+    ///
+    /// This is a collective operation: all processes of this communicator must call it.
+    ///
+    /// `key` -- Determines the order of the ranks in the sub-communicator (ties are broken by the rank in this communicator)
+    pub fn split_shared(&self, key: usize) -> Result<Communicator, StrError> {
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_split_shared(self.handle, to_i32(key), &mut ext_comm);
+            if status != C_MPI_SUCCESS || ext_comm.is_null() {
+                return Err("MPI failed to split the communicator (shared memory)");
+            }
+        }
+        Ok(Communicator { handle: ext_comm })
+    }
+
+    /// Returns the group of processes of this communicator
+    pub fn group(&self) -> Result<Group, StrError> {
+        Group::register(|new_group| unsafe { comm_group(self.handle, new_group) })
+    }
+
+    /// Creates a communicator with the processes of a group (a subset of the processes of this communicator)
+    ///
+    /// This is a collective operation: all processes of this communicator must call it (with the same group).
+    ///
+    /// Returns None on the processes that are not members of the group.
+    pub fn from_group(&self, group: &Group) -> Result<Option<Communicator>, StrError> {
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_from_group(self.handle, group.ext(), &mut ext_comm);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to create the communicator from the group");
+            }
+        }
+        if ext_comm.is_null() {
+            return Ok(None);
+        }
+        Ok(Some(Communicator { handle: ext_comm }))
+    }
+
+    /// Creates a communicator with the processes of a group (only the members call it)
+    ///
+    /// Unlike [Communicator::from_group], only the members of the group participate.
+    ///
+    /// `tag` -- Distinguishes concurrent calls with overlapping groups (must be non-negative)
+    pub fn from_group_members(&self, group: &Group, tag: i32) -> Result<Communicator, StrError> {
+        if group.rank()?.is_none() {
+            return Err("this process is not a member of the group");
+        }
+        if tag < 0 {
+            return Err("the tag must be non-negative");
+        }
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_from_group_members(self.handle, group.ext(), tag, &mut ext_comm);
+            if status != C_MPI_SUCCESS || ext_comm.is_null() {
+                return Err("MPI failed to create the communicator from the group (members only)");
+            }
+        }
+        Ok(Communicator { handle: ext_comm })
+    }
+
+    /// Duplicates the communicator (with the same group but a new context)
+    ///
+    /// The messages sent in the new communicator never match the messages of the original one;
+    /// thus, each library may use its own duplicate without colliding tags.
+    ///
+    /// This is a collective operation: all processes of this communicator must call it.
+    pub fn duplicate(&self) -> Result<Communicator, StrError> {
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_duplicate(self.handle, &mut ext_comm);
+            if status != C_MPI_SUCCESS || ext_comm.is_null() {
+                return Err("MPI failed to duplicate the communicator");
+            }
+        }
+        Ok(Communicator { handle: ext_comm })
+    }
+
+    /// Starts the duplication of the communicator (non-blocking)
+    ///
+    /// The new communicator cannot be used until [PendingCommunicator::wait] returns it.
+    ///
+    /// This is a collective operation: all processes of this communicator must call it.
+    pub fn idup(&self) -> Result<PendingCommunicator, StrError> {
+        let request = Request::new()?;
+        let mut ext_comm: *mut ExtCommunicator = std::ptr::null_mut();
+        unsafe {
+            let status = comm_idup(self.handle, request.handle, &mut ext_comm);
+            if status != C_MPI_SUCCESS || ext_comm.is_null() {
+                return Err("MPI failed to duplicate the communicator (non-blocking)");
+            }
+        }
+        Ok(PendingCommunicator { request, comm: Communicator { handle: ext_comm } })
+    }
+
+    /// Compares this communicator with another one
+    ///
+    /// Returns [MpiComparison::Identical] if both refer to the same communicator, [MpiComparison::Congruent] if
+    /// they have the same members in the same order (e.g., duplicates), [MpiComparison::Similar] if they have
+    /// the same members in a different order, and [MpiComparison::Unequal] otherwise.
+    pub fn compare(&self, other: &Communicator) -> Result<MpiComparison, StrError> {
+        let mut index: i32 = 0;
+        unsafe {
+            let status = comm_compare(self.handle, other.handle, &mut index);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to compare the communicators");
+            }
+        }
+        Ok(MpiComparison::from_index(index))
+    }
+
+    /// Sets the name of the communicator (e.g., shown by debuggers and error messages)
+    pub fn set_name(&self, name: &str) -> Result<(), StrError> {
+        let c_name = std::ffi::CString::new(name).map_err(|_| "the name must not contain nul characters")?;
+        unsafe {
+            let status = comm_set_name(self.handle, c_name.as_ptr());
+            if status == C_MPI_ERROR_NAME_TOO_LONG {
+                return Err("the name of the communicator is too long");
+            }
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to set the name of the communicator");
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the communicator (e.g., "MPI_COMM_WORLD")
+    pub fn name(&self) -> Result<String, StrError> {
+        const MAX_LEN: usize = 256;
+        let mut bytes = vec![0_u8; MAX_LEN];
+        let mut len: i32 = 0;
+        unsafe {
+            let status = comm_get_name(self.handle, to_i32(MAX_LEN), bytes.as_mut_ptr(), &mut len);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to get the name of the communicator");
+            }
+        }
+        bytes.truncate(len as usize);
+        Ok(bytes_to_string_lossy(&bytes))
     }
 
     /// Terminates the MPI execution environment
@@ -200,583 +503,553 @@ impl Communicator {
         Ok(size as usize)
     }
 
-    //  broadcast --------------------------------------------------------------------------------------
+    // broadcast ------------------------------------------------------------------------------------------
 
     /// Broadcasts a message from sender to all other processes in the group
-    pub fn broadcast_i32(&mut self, sender: usize, x: &mut [i32]) -> Result<(), StrError> {
+    pub fn broadcast<T: MpiData>(&mut self, sender: usize, x: &mut [T]) -> Result<(), StrError> {
         unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::I32.n());
+            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, T::type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast i32 array");
+                return Err("MPI failed to broadcast array");
             }
         }
         Ok(())
     }
 
     /// Broadcasts a message from sender to all other processes in the group
-    pub fn broadcast_i64(&mut self, sender: usize, x: &mut [i64]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::I64.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast i64 array");
-            }
-        }
-        Ok(())
+    pub fn broadcast_i32(&mut self, sender: usize, x: &mut [i32]) -> Result<(), StrError> {
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
-    pub fn broadcast_u32(&mut self, sender: usize, x: &mut [u32]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::U32.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast u32 array");
-            }
-        }
-        Ok(())
+    pub fn broadcast_i64(&mut self, sender: usize, x: &mut [i64]) -> Result<(), StrError> {
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
-    pub fn broadcast_u64(&mut self, sender: usize, x: &mut [u64]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::U64.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast u64 array");
-            }
-        }
-        Ok(())
+    pub fn broadcast_u32(&mut self, sender: usize, x: &mut [u32]) -> Result<(), StrError> {
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
-    #[cfg(target_pointer_width = "32")]
-    pub fn broadcast_usize(&mut self, sender: usize, x: &mut [usize]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.ext_comm, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::U32.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast usize array");
-            }
-        }
-        Ok(())
+    pub fn broadcast_u64(&mut self, sender: usize, x: &mut [u64]) -> Result<(), StrError> {
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
-    #[cfg(target_pointer_width = "64")]
     pub fn broadcast_usize(&mut self, sender: usize, x: &mut [usize]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::U64.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast usize array");
-            }
-        }
-        Ok(())
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
     pub fn broadcast_f32(&mut self, sender: usize, x: &mut [f32]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::F32.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast f32 array");
-            }
-        }
-        Ok(())
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
     pub fn broadcast_f64(&mut self, sender: usize, x: &mut [f64]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::F64.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast f64 array");
-            }
-        }
-        Ok(())
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
     pub fn broadcast_c32(&mut self, sender: usize, x: &mut [Complex32]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::C32.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast Complex32 array");
-            }
-        }
-        Ok(())
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
     pub fn broadcast_c64(&mut self, sender: usize, x: &mut [Complex64]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::C64.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast Complex64 array");
-            }
-        }
-        Ok(())
+        self.broadcast(sender, x)
     }
 
     /// Broadcasts a message from sender to all other processes in the group
     pub fn broadcast_bytes(&mut self, sender: usize, x: &mut [u8]) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(x.len()), x.as_mut_ptr() as *mut c_void, MpiType::BYT.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to broadcast bytes array");
-            }
-        }
-        Ok(())
+        self.broadcast(sender, x)
     }
 
-    // reduce -----------------------------------------------------------------------------------------
+    // reduce ---------------------------------------------------------------------------------------------
 
     /// Reduces values on all processes within a group
-    pub fn reduce_i32(&mut self, root: usize, dest: &mut [i32], orig: &[i32], op: MpiOpInt) -> Result<(), StrError> {
+    pub fn reduce<T: MpiReducible>(&mut self, root: usize, dest: &mut [T], orig: &[T], op: T::Op) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I32.n(), op.n());
+            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce i32 array");
+                return Err("MPI failed to reduce array");
             }
         }
         Ok(())
     }
 
     /// Reduces values on all processes within a group
-    pub fn reduce_i64(&mut self, root: usize, dest: &mut [i64], orig: &[i64], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I64.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce i64 array");
-            }
-        }
-        Ok(())
+    pub fn reduce_i32(&mut self, root: usize, dest: &mut [i32], orig: &[i32], op: MpiOpInt) -> Result<(), StrError> {
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
-    pub fn reduce_u32(&mut self, root: usize, dest: &mut [u32], orig: &[u32], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce u32 array");
-            }
-        }
-        Ok(())
+    pub fn reduce_i64(&mut self, root: usize, dest: &mut [i64], orig: &[i64], op: MpiOpInt) -> Result<(), StrError> {
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
-    pub fn reduce_u64(&mut self, root: usize, dest: &mut [u64], orig: &[u64], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce u64 array");
-            }
-        }
-        Ok(())
+    pub fn reduce_u32(&mut self, root: usize, dest: &mut [u32], orig: &[u32], op: MpiOpInt) -> Result<(), StrError> {
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
-    #[cfg(target_pointer_width = "32")]
-    pub fn reduce_usize(&mut self, root: usize, dest: &mut [usize], orig: &[usize], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce usize array");
-            }
-        }
-        Ok(())
+    pub fn reduce_u64(&mut self, root: usize, dest: &mut [u64], orig: &[u64], op: MpiOpInt) -> Result<(), StrError> {
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
-    #[cfg(target_pointer_width = "64")]
     pub fn reduce_usize(&mut self, root: usize, dest: &mut [usize], orig: &[usize], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce usize array");
-            }
-        }
-        Ok(())
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
     pub fn reduce_f32(&mut self, root: usize, dest: &mut [f32], orig: &[f32], op: MpiOpReal) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F32.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce f32 array");
-            }
-        }
-        Ok(())
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
     pub fn reduce_f64(&mut self, root: usize, dest: &mut [f64], orig: &[f64], op: MpiOpReal) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F64.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce f64 array");
-            }
-        }
-        Ok(())
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
     pub fn reduce_c32(&mut self, root: usize, dest: &mut [Complex32], orig: &[Complex32], op: MpiOpComplex) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C32.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce Complex32 array");
-            }
-        }
-        Ok(())
+        self.reduce(root, dest, orig, op)
     }
 
     /// Reduces values on all processes within a group
     pub fn reduce_c64(&mut self, root: usize, dest: &mut [Complex64], orig: &[Complex64], op: MpiOpComplex) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C64.n(), op.n());
+        self.reduce(root, dest, orig, op)
+    }
+
+    /// Reduces values on all processes within a group
+    pub fn reduce_bytes(&mut self, root: usize, dest: &mut [u8], orig: &[u8], op: MpiOpByte) -> Result<(), StrError> {
+        self.reduce(root, dest, orig, op)
+    }
+
+    /// Reduces values on all processes within a group (in place)
+    ///
+    /// `data` -- Data to be reduced; on the root process, it is replaced by the result
+    /// (on the other processes, it is unchanged)
+    pub fn reduce_in_place<T: MpiReducible>(&mut self, root: usize, data: &mut [T], op: T::Op) -> Result<(), StrError> {
+        let rank = self.rank()?;
+        unsafe {
+            let status = if rank == root {
+                comm_reduce_in_place_im_root(self.handle, to_i32(root), to_i32(data.len()), data.as_mut_ptr() as *mut c_void, T::type_index(), op.op_index())
+            } else {
+                comm_reduce_in_place_im_not_root(self.handle, to_i32(root), to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index(), op.op_index())
+            };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce Complex64 array");
+                return Err("MPI failed to reduce array");
             }
         }
         Ok(())
     }
 
-    /// Reduces values on all processes within a group
-    pub fn reduce_bytes(&mut self, root: usize, dest: &mut [u8], orig: &[u8], op: MpiOpByte) -> Result<(), StrError> {
+    // allreduce ------------------------------------------------------------------------------------------
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce<T: MpiReducible>(&mut self, dest: &mut [T], orig: &[T], op: T::Op) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_reduce(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::BYT.n(), op.n());
+            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to reduce bytes array");
+                return Err("MPI failed to (all) reduce array");
             }
         }
         Ok(())
     }
 
-    // allreduce -----------------------------------------------------------------------------------------
-
     /// Combines values from all processes and distributes the result back to all processes
     pub fn allreduce_i32(&mut self, dest: &mut [i32], orig: &[i32], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I32.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce i32 array");
-            }
-        }
-        Ok(())
+        self.allreduce(dest, orig, op)
     }
 
     /// Combines values from all processes and distributes the result back to all processes
     pub fn allreduce_i64(&mut self, dest: &mut [i64], orig: &[i64], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I64.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce i64 array");
-            }
-        }
-        Ok(())
+        self.allreduce(dest, orig, op)
     }
 
     /// Combines values from all processes and distributes the result back to all processes
     pub fn allreduce_u32(&mut self, dest: &mut [u32], orig: &[u32], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
-        unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n(), op.n());
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce u32 array");
-            }
-        }
-        Ok(())
+        self.allreduce(dest, orig, op)
     }
 
     /// Combines values from all processes and distributes the result back to all processes
     pub fn allreduce_u64(&mut self, dest: &mut [u64], orig: &[u64], op: MpiOpInt) -> Result<(), StrError> {
-        if dest.len() != orig.len() {
-            return Err("arrays must have the same size");
-        }
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce_usize(&mut self, dest: &mut [usize], orig: &[usize], op: MpiOpInt) -> Result<(), StrError> {
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce_f32(&mut self, dest: &mut [f32], orig: &[f32], op: MpiOpReal) -> Result<(), StrError> {
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce_f64(&mut self, dest: &mut [f64], orig: &[f64], op: MpiOpReal) -> Result<(), StrError> {
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce_c32(&mut self, dest: &mut [Complex32], orig: &[Complex32], op: MpiOpComplex) -> Result<(), StrError> {
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce_c64(&mut self, dest: &mut [Complex64], orig: &[Complex64], op: MpiOpComplex) -> Result<(), StrError> {
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes
+    pub fn allreduce_bytes(&mut self, dest: &mut [u8], orig: &[u8], op: MpiOpByte) -> Result<(), StrError> {
+        self.allreduce(dest, orig, op)
+    }
+
+    /// Combines values from all processes and distributes the result back to all processes (in place)
+    ///
+    /// `data` -- Data to be combined; it is replaced by the result
+    pub fn allreduce_in_place<T: MpiReducible>(&mut self, data: &mut [T], op: T::Op) -> Result<(), StrError> {
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n(), op.n());
+            let status = comm_allreduce_in_place(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce u64 array");
+                return Err("MPI failed to (all) reduce array");
             }
         }
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    #[cfg(target_pointer_width = "32")]
-    pub fn allreduce_usize(&mut self, dest: &mut [usize], orig: &[usize], op: MpiOpInt) -> Result<(), StrError> {
+    // scan -----------------------------------------------------------------------------------------------
+
+    /// Computes the inclusive prefix reduction of values on all processes
+    ///
+    /// On process `i`, `dest` holds the reduction of the `orig` values of processes `0..=i`.
+    pub fn scan<T: MpiReducible>(&mut self, dest: &mut [T], orig: &[T], op: T::Op) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n(), op.n());
+            let status = comm_scan(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce usize array");
+                return Err("MPI failed to scan array");
             }
         }
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    #[cfg(target_pointer_width = "64")]
-    pub fn allreduce_usize(&mut self, dest: &mut [usize], orig: &[usize], op: MpiOpInt) -> Result<(), StrError> {
+    /// Computes the exclusive prefix reduction of values on all processes
+    ///
+    /// On process `i > 0`, `dest` holds the reduction of the `orig` values of processes `0..i`.
+    ///
+    /// **Note:** On process 0, `dest` is filled with zeros (MPI leaves it undefined).
+    pub fn exscan<T: MpiReducible>(&mut self, dest: &mut [T], orig: &[T], op: T::Op) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n(), op.n());
+            let status = comm_exscan(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce usize array");
+                return Err("MPI failed to (exclusive) scan array");
             }
         }
+        if self.rank()? == 0 {
+            // all reducible types are zero when all bits are zero
+            unsafe { std::ptr::write_bytes(dest.as_mut_ptr(), 0, dest.len()) };
+        }
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    pub fn allreduce_f32(&mut self, dest: &mut [f32], orig: &[f32], op: MpiOpReal) -> Result<(), StrError> {
+    // user-defined reductions ------------------------------------------------------------------------------
+
+    /// Reduces values on all processes within a group using a user-defined operation
+    pub fn reduce_with<T: MpiData>(&mut self, root: usize, dest: &mut [T], orig: &[T], op: &UserOp<T>) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F32.n(), op.n());
+            let status = comm_reduce_user_op(self.handle, to_i32(root), to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.ext(), op.context());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce f32 array");
+                return Err("MPI failed to reduce array");
             }
         }
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    pub fn allreduce_f64(&mut self, dest: &mut [f64], orig: &[f64], op: MpiOpReal) -> Result<(), StrError> {
+    /// Combines values from all processes using a user-defined operation and distributes the result back to all processes
+    pub fn allreduce_with<T: MpiData>(&mut self, dest: &mut [T], orig: &[T], op: &UserOp<T>) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F64.n(), op.n());
+            let status = comm_allreduce_user_op(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.ext(), op.context());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce f64 array");
+                return Err("MPI failed to (all) reduce array");
             }
         }
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    pub fn allreduce_c32(&mut self, dest: &mut [Complex32], orig: &[Complex32], op: MpiOpComplex) -> Result<(), StrError> {
+    /// Computes the inclusive prefix reduction of values on all processes using a user-defined operation
+    pub fn scan_with<T: MpiData>(&mut self, dest: &mut [T], orig: &[T], op: &UserOp<T>) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C32.n(), op.n());
+            let status = comm_scan_user_op(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.ext(), op.context());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce Complex32 array");
+                return Err("MPI failed to scan array");
             }
         }
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    pub fn allreduce_c64(&mut self, dest: &mut [Complex64], orig: &[Complex64], op: MpiOpComplex) -> Result<(), StrError> {
+    // reductions with location ----------------------------------------------------------------------------
+
+    /// Reduces (value, index) pairs on all processes within a group, finding the extreme values and their indices
+    ///
+    /// `dest` -- Buffer to store the result (root only; unchanged on the other processes)
+    /// `orig` -- The (value, index) pairs of this process (e.g., with the index equal to the rank)
+    pub fn reduce_loc<T: MpiLocReducible>(&mut self, root: usize, dest: &mut [(T, usize)], orig: &[(T, usize)], op: MpiOpLoc) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
+        let mut d = to_pairs(dest);
+        let o = to_pairs(orig);
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C64.n(), op.n());
+            let status = comm_reduce(self.handle, to_i32(root), to_i32(o.len()), d.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, T::pair_type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce Complex64 array");
+                return Err("MPI failed to reduce array");
             }
         }
+        from_pairs(dest, &d);
         Ok(())
     }
 
-    /// Combines values from all processes and distributes the result back to all processes
-    pub fn allreduce_bytes(&mut self, dest: &mut [u8], orig: &[u8], op: MpiOpByte) -> Result<(), StrError> {
+    /// Combines (value, index) pairs from all processes, finding the extreme values and their indices
+    ///
+    /// `dest` -- Buffer to store the result
+    /// `orig` -- The (value, index) pairs of this process (e.g., with the index equal to the rank)
+    pub fn allreduce_loc<T: MpiLocReducible>(&mut self, dest: &mut [(T, usize)], orig: &[(T, usize)], op: MpiOpLoc) -> Result<(), StrError> {
         if dest.len() != orig.len() {
             return Err("arrays must have the same size");
         }
+        let mut d = to_pairs(dest);
+        let o = to_pairs(orig);
         unsafe {
-            let status = comm_allreduce(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::BYT.n(), op.n());
+            let status = comm_allreduce(self.handle, to_i32(o.len()), d.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, T::pair_type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to (all) reduce bytes array");
+                return Err("MPI failed to (all) reduce array");
             }
         }
+        from_pairs(dest, &d);
         Ok(())
     }
 
-    // getters -------------------------------------------------------------------------------------------
+    /// Finds the maximum values over all processes and the ranks holding them
+    ///
+    /// Returns the (value, rank) pairs (if the maximum is not unique, the lowest rank is returned).
+    pub fn allreduce_max_loc<T: MpiLocReducible>(&mut self, orig: &[T]) -> Result<Vec<(T, usize)>, StrError> {
+        self.allreduce_loc_rank(orig, MpiOpLoc::Max)
+    }
+
+    /// Finds the minimum values over all processes and the ranks holding them
+    ///
+    /// Returns the (value, rank) pairs (if the minimum is not unique, the lowest rank is returned).
+    pub fn allreduce_min_loc<T: MpiLocReducible>(&mut self, orig: &[T]) -> Result<Vec<(T, usize)>, StrError> {
+        self.allreduce_loc_rank(orig, MpiOpLoc::Min)
+    }
 
-    /// Returns the status of the last receive call
-    pub fn get_receive_status(&mut self) -> (i32, i32, i32) {
-        let mut source = 0;
-        let mut tag = 0;
-        let mut error = 0;
-        unsafe {
-            comm_get_receive_status(self.handle, &mut source, &mut tag, &mut error);
-        }
-        (source, tag, error)
+    /// Combines the values paired with the rank of this process
+    fn allreduce_loc_rank<T: MpiLocReducible>(&mut self, orig: &[T], op: MpiOpLoc) -> Result<Vec<(T, usize)>, StrError> {
+        let rank = self.rank()?;
+        let pairs: Vec<_> = orig.iter().map(|&value| (value, rank)).collect();
+        let mut dest = pairs.clone();
+        self.allreduce_loc(&mut dest, &pairs, op)?;
+        Ok(dest)
     }
 
-    // send ----------------------------------------------------------------------------------------------
+    // reduce-scatter -------------------------------------------------------------------------------------
 
-    /// Performs a standard-mode blocking send
-    pub fn send_i32(&mut self, data: &[i32], to_rank: usize, tag: i32) -> Result<(), StrError> {
+    /// Reduces values on all processes and scatters equal blocks of the result
+    ///
+    /// Process `i` receives the i-th block of the reduction of the `orig` arrays.
+    ///
+    /// `dest` -- Buffer to store the block received by this process
+    /// `orig` -- Data to be reduced (the length must equal the number of processors times `dest.len()`)
+    pub fn reduce_scatter_block<T: MpiReducible>(&mut self, dest: &mut [T], orig: &[T], op: T::Op) -> Result<(), StrError> {
+        let size = self.size()?;
+        if orig.len() != size * dest.len() {
+            return Err("orig.len() must equal the number of processors times dest.len()");
+        }
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::I32.n(), to_i32(to_rank), tag);
+            let status = comm_reduce_scatter_block(self.handle, to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send i32 array");
+                return Err("MPI failed to reduce and scatter array");
             }
         }
         Ok(())
     }
 
-    /// Performs a standard-mode blocking send
-    pub fn send_i64(&mut self, data: &[i64], to_rank: usize, tag: i32) -> Result<(), StrError> {
+    /// Reduces values on all processes and scatters varying blocks of the result
+    ///
+    /// Process `i` receives `counts[i]` values of the reduction of the `orig` arrays;
+    /// the block of process `i` follows the block of process `i-1`.
+    ///
+    /// `dest` -- Buffer to store the block received by this process (the length must equal `counts[rank]`)
+    /// `orig` -- Data to be reduced (the length must equal the sum of `counts`)
+    /// `counts` -- Number of elements received by each process
+    pub fn reduce_scatter<T: MpiReducible>(&mut self, dest: &mut [T], orig: &[T], counts: &[usize], op: T::Op) -> Result<(), StrError> {
+        let rank = self.rank()?;
+        let size = self.size()?;
+        if counts.len() != size {
+            return Err("counts.len() must equal the number of processors");
+        }
+        if orig.len() != counts.iter().sum::<usize>() {
+            return Err("orig.len() must equal the sum of counts");
+        }
+        if dest.len() != counts[rank] {
+            return Err("dest.len() must equal counts[rank]");
+        }
+        let c = to_i32_vec(counts);
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::I64.n(), to_i32(to_rank), tag);
+            let status = comm_reduce_scatter(self.handle, dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, c.as_ptr(), T::type_index(), op.op_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send i64 array");
+                return Err("MPI failed to reduce and scatter array");
             }
         }
         Ok(())
     }
 
+    // send ----------------------------------------------------------------------------------------------
+
     /// Performs a standard-mode blocking send
-    pub fn send_u32(&mut self, data: &[u32], to_rank: usize, tag: i32) -> Result<(), StrError> {
+    pub fn send<T: MpiData>(&mut self, data: &[T], to_rank: usize, tag: i32) -> Result<(), StrError> {
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::U32.n(), to_i32(to_rank), tag);
+            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send u32 array");
+                return Err("MPI failed to send array");
             }
         }
         Ok(())
     }
 
     /// Performs a standard-mode blocking send
-    pub fn send_u64(&mut self, data: &[u64], to_rank: usize, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::U64.n(), to_i32(to_rank), tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send u64 array");
-            }
-        }
-        Ok(())
+    pub fn send_i32(&mut self, data: &[i32], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
     }
 
     /// Performs a standard-mode blocking send
-    #[cfg(target_pointer_width = "32")]
-    pub fn send_usize(&mut self, data: &[usize], to_rank: usize, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::U32.n(), to_i32(to_rank), tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send usize array");
-            }
-        }
-        Ok(())
+    pub fn send_i64(&mut self, data: &[i64], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
+    }
+
+    /// Performs a standard-mode blocking send
+    pub fn send_u32(&mut self, data: &[u32], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
+    }
+
+    /// Performs a standard-mode blocking send
+    pub fn send_u64(&mut self, data: &[u64], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
     }
 
     /// Performs a standard-mode blocking send
-    #[cfg(target_pointer_width = "64")]
     pub fn send_usize(&mut self, data: &[usize], to_rank: usize, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::U64.n(), to_i32(to_rank), tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send usize array");
-            }
-        }
-        Ok(())
+        self.send(data, to_rank, tag)
     }
 
     /// Performs a standard-mode blocking send
     pub fn send_f32(&mut self, data: &[f32], to_rank: usize, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::F32.n(), to_i32(to_rank), tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send f32 array");
-            }
-        }
-        Ok(())
+        self.send(data, to_rank, tag)
     }
 
     /// Performs a standard-mode blocking send
     pub fn send_f64(&mut self, data: &[f64], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
+    }
+
+    /// Performs a standard-mode blocking send
+    pub fn send_c32(&mut self, data: &[Complex32], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
+    }
+
+    /// Performs a standard-mode blocking send
+    pub fn send_c64(&mut self, data: &[Complex64], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
+    }
+
+    /// Performs a standard-mode blocking send
+    pub fn send_bytes(&mut self, data: &[u8], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(data, to_rank, tag)
+    }
+
+    /// Performs a synchronous-mode blocking send
+    ///
+    /// The send completes only after the matching receive has started; thus, this function
+    /// is useful to detect a hidden reliance on the buffering of standard-mode sends.
+    pub fn ssend<T: MpiData>(&mut self, data: &[T], to_rank: usize, tag: i32) -> Result<(), StrError> {
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::F64.n(), to_i32(to_rank), tag);
+            let status = comm_ssend(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send f64 array");
+                return Err("MPI failed to send array (synchronous)");
             }
         }
         Ok(())
     }
 
-    /// Performs a standard-mode blocking send
-    pub fn send_c32(&mut self, data: &[Complex32], to_rank: usize, tag: i32) -> Result<(), StrError> {
+    /// Performs a buffered-mode blocking send
+    ///
+    /// The data is copied into the buffer attached by [crate::AttachedBuffer]; thus, the send completes
+    /// locally. The attached buffer must be large enough (see [Communicator::bsend_size]).
+    pub fn bsend<T: MpiData>(&mut self, data: &[T], to_rank: usize, tag: i32) -> Result<(), StrError> {
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::C32.n(), to_i32(to_rank), tag);
+            let status = comm_bsend(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send Complex32 array");
+                return Err("MPI failed to send array (buffered)");
             }
         }
         Ok(())
     }
 
-    /// Performs a standard-mode blocking send
-    pub fn send_c64(&mut self, data: &[Complex64], to_rank: usize, tag: i32) -> Result<(), StrError> {
+    /// Performs a ready-mode blocking send
+    ///
+    /// **Important:** The matching receive must have been posted already; otherwise, the result is undefined.
+    pub fn rsend<T: MpiData>(&mut self, data: &[T], to_rank: usize, tag: i32) -> Result<(), StrError> {
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::C64.n(), to_i32(to_rank), tag);
+            let status = comm_rsend(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send Complex64 array");
+                return Err("MPI failed to send array (ready)");
             }
         }
         Ok(())
     }
 
-    /// Performs a standard-mode blocking send
-    pub fn send_bytes(&mut self, data: &[u8], to_rank: usize, tag: i32) -> Result<(), StrError> {
+    /// Returns the number of bytes of the attached buffer required by a buffered-mode send
+    ///
+    /// The size accounts for the MPI_BSEND_OVERHEAD. For many messages pending at the same
+    /// time, the size of the attached buffer must be the sum of the sizes of each message.
+    ///
+    /// `n` -- Number of elements of type `T` in the message
+    pub fn bsend_size<T: MpiData>(&mut self, n: usize) -> Result<usize, StrError> {
+        let mut size: i32 = 0;
         unsafe {
-            let status = comm_send(self.handle, to_i32(data.len()), data.as_ptr() as *const c_void, MpiType::BYT.n(), to_i32(to_rank), tag);
+            let status = comm_bsend_size(self.handle, to_i32(n), T::type_index(), &mut size);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to send bytes array");
+                return Err("MPI failed to compute the size required by the buffered send");
             }
         }
-        Ok(())
+        Ok(size as usize)
     }
 
     // receive -------------------------------------------------------------------------------------------
@@ -786,14 +1059,17 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_i32(&mut self, data: &mut [i32], from_rank: i32, tag: i32) -> Result<(), StrError> {
+    ///
+    /// Returns the status of the receive operation with `count` given in number of elements of type `T`.
+    pub fn receive<T: MpiData>(&mut self, data: &mut [T], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::I32.n(), from_rank, tag);
+            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, T::type_index(), from_rank, tag, &mut ext_status);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive i32 array");
+                return Err("MPI failed to receive array");
             }
         }
-        Ok(())
+        Ok(Status::from(ext_status))
     }
 
     /// Performs a standard-mode blocking receive
@@ -801,14 +1077,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_i64(&mut self, data: &mut [i64], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::I64.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive i64 array");
-            }
-        }
-        Ok(())
+    pub fn receive_i32(&mut self, data: &mut [i32], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -816,14 +1086,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_u32(&mut self, data: &mut [u32], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::U32.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive u32 array");
-            }
-        }
-        Ok(())
+    pub fn receive_i64(&mut self, data: &mut [i64], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -831,14 +1095,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_u64(&mut self, data: &mut [u64], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::U64.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive u64 array");
-            }
-        }
-        Ok(())
+    pub fn receive_u32(&mut self, data: &mut [u32], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -846,15 +1104,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    #[cfg(target_pointer_width = "32")]
-    pub fn receive_usize(&mut self, data: &mut [usize], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::U32.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive usize array");
-            }
-        }
-        Ok(())
+    pub fn receive_u64(&mut self, data: &mut [u64], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -862,15 +1113,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    #[cfg(target_pointer_width = "64")]
-    pub fn receive_usize(&mut self, data: &mut [usize], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::U64.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive usize array");
-            }
-        }
-        Ok(())
+    pub fn receive_usize(&mut self, data: &mut [usize], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -878,14 +1122,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_f32(&mut self, data: &mut [f32], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::F32.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive f32 array");
-            }
-        }
-        Ok(())
+    pub fn receive_f32(&mut self, data: &mut [f32], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -893,14 +1131,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_f64(&mut self, data: &mut [f64], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::F64.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive f64 array");
-            }
-        }
-        Ok(())
+    pub fn receive_f64(&mut self, data: &mut [f64], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -908,14 +1140,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_c32(&mut self, data: &mut [Complex32], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::C32.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive Complex32 array");
-            }
-        }
-        Ok(())
+    pub fn receive_c32(&mut self, data: &mut [Complex32], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -923,14 +1149,8 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_c64(&mut self, data: &mut [Complex64], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::C64.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive Complex64 array");
-            }
-        }
-        Ok(())
+    pub fn receive_c64(&mut self, data: &mut [Complex64], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
     /// Performs a standard-mode blocking receive
@@ -938,428 +1158,623 @@ impl Communicator {
     /// `data` -- Buffer to store the received data
     /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
     /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
-    pub fn receive_bytes(&mut self, data: &mut [u8], from_rank: i32, tag: i32) -> Result<(), StrError> {
-        unsafe {
-            let status = comm_receive(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, MpiType::BYT.n(), from_rank, tag);
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to receive bytes array");
-            }
-        }
-        Ok(())
+    pub fn receive_bytes(&mut self, data: &mut [u8], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        self.receive(data, from_rank, tag)
     }
 
-    // gather -------------------------------------------------------------------------------------------
+    // send-receive ------------------------------------------------------------------------------------
 
-    pub fn gather_i32(&mut self, root: usize, dest: Option<&mut [i32]>, orig: &[i32]) -> Result<(), StrError> {
+    /// Sends a message and receives a message in a single (deadlock-free) operation
+    ///
+    /// The send and receive buffers may have different types and lengths.
+    ///
+    /// `send_data` -- Data to be sent
+    /// `to_rank` -- Rank of the destination process
+    /// `send_tag` -- Tag of the sent message
+    /// `recv_data` -- Buffer to store the received data
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `recv_tag` -- Tag of the received message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns the status of the receive operation.
+    pub fn sendrecv<S: MpiData, R: MpiData>(&mut self, send_data: &[S], to_rank: usize, send_tag: i32, recv_data: &mut [R], from_rank: i32, recv_tag: i32) -> Result<Status, StrError> {
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I32.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::I32.n()),
-            };
+            let status = comm_sendrecv(
+                self.handle,
+                to_i32(send_data.len()),
+                send_data.as_ptr() as *const c_void,
+                S::type_index(),
+                to_i32(to_rank),
+                send_tag,
+                to_i32(recv_data.len()),
+                recv_data.as_mut_ptr() as *mut c_void,
+                R::type_index(),
+                from_rank,
+                recv_tag,
+                &mut ext_status,
+            );
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather i32 arrays");
+                return Err("MPI failed to send and receive arrays");
             }
         }
-        Ok(())
+        Ok(Status::from(ext_status))
     }
 
-    pub fn gather_i64(&mut self, root: usize, dest: Option<&mut [i64]>, orig: &[i64]) -> Result<(), StrError> {
+    /// Sends a message and receives a message using a single buffer (deadlock-free)
+    ///
+    /// The received data replaces the sent data; thus, both messages must have the same length.
+    ///
+    /// `data` -- Data to be sent; then, buffer to store the received data
+    /// `to_rank` -- Rank of the destination process
+    /// `send_tag` -- Tag of the sent message
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `recv_tag` -- Tag of the received message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns the status of the receive operation.
+    pub fn sendrecv_replace<T: MpiData>(&mut self, data: &mut [T], to_rank: usize, send_tag: i32, from_rank: i32, recv_tag: i32) -> Result<Status, StrError> {
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I64.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::I64.n()),
-            };
+            let status = comm_sendrecv_replace(self.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, T::type_index(), to_i32(to_rank), send_tag, from_rank, recv_tag, &mut ext_status);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather i64 arrays");
+                return Err("MPI failed to send and receive array (replace)");
             }
         }
-        Ok(())
+        Ok(Status::from(ext_status))
     }
 
-    pub fn gather_u32(&mut self, root: usize, dest: Option<&mut [u32]>, orig: &[u32]) -> Result<(), StrError> {
+    // probe -------------------------------------------------------------------------------------------
+
+    /// Waits for a matching message without receiving it
+    ///
+    /// Returns the status of the message with `count` given in number of elements of type `T`.
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    pub fn probe<T: MpiData>(&mut self, from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::U32.n()),
-            };
+            let status = comm_probe(self.handle, from_rank, tag, T::type_index(), &mut ext_status);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather u32 arrays");
+                return Err("MPI failed to probe for a message");
             }
         }
-        Ok(())
+        Ok(Status::from(ext_status))
     }
 
-    pub fn gather_u64(&mut self, root: usize, dest: Option<&mut [u64]>, orig: &[u64]) -> Result<(), StrError> {
+    /// Checks whether a matching message is available or not (without receiving it)
+    ///
+    /// Returns the status of the message with `count` given in number of elements of type `T`
+    /// or None if there is no matching message yet.
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    pub fn iprobe<T: MpiData>(&mut self, from_rank: i32, tag: i32) -> Result<Option<Status>, StrError> {
+        let mut flag: i32 = 0;
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::U64.n()),
-            };
+            let status = comm_iprobe(self.handle, from_rank, tag, T::type_index(), &mut flag, &mut ext_status);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather u64 arrays");
+                return Err("MPI failed to probe for a message (non-blocking)");
             }
         }
-        Ok(())
+        if flag == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(Status::from(ext_status)))
+        }
     }
 
-    #[cfg(target_pointer_width = "32")]
-    pub fn gather_usize(&mut self, root: usize, dest: Option<&mut [usize]>, orig: &[usize]) -> Result<(), StrError> {
+    /// Receives a message of unknown length into a newly allocated vector
+    ///
+    /// The message is probed first (matched probe); then the vector is allocated with the exact incoming
+    /// size and that same message is received (no other receive, e.g., from another thread, can take it).
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    pub fn receive_vec<T: MpiData>(&mut self, from_rank: i32, tag: i32) -> Result<(Vec<T>, Status), StrError> {
+        let mut data: Vec<T> = Vec::new();
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::U32.n()),
-            };
+            let context = &mut data as *mut Vec<T> as *mut c_void;
+            let status = comm_mprobe_receive(self.handle, from_rank, tag, T::type_index(), allocate_vec::<T>, context, &mut ext_status);
+            if status == C_MPI_ERROR_MESSAGE_SIZE {
+                return Err("the message size is not a multiple of the datatype size");
+            }
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather usize arrays");
+                return Err("MPI failed to receive array");
             }
+            if ext_status.count < 0 || ext_status.count as usize > data.capacity() {
+                return Err("the number of received elements does not match the probed message");
+            }
+            data.set_len(ext_status.count as usize);
         }
-        Ok(())
+        Ok((data, Status::from(ext_status)))
     }
 
-    #[cfg(target_pointer_width = "64")]
-    pub fn gather_usize(&mut self, root: usize, dest: Option<&mut [usize]>, orig: &[usize]) -> Result<(), StrError> {
+    // non-blocking send/receive ------------------------------------------------------------------------
+
+    /// Starts a standard-mode non-blocking send
+    ///
+    /// The returned request borrows `data` until the operation completes.
+    ///
+    /// # Safety
+    ///
+    /// The request (and any [crate::RequestSet] holding it) must be dropped, waited for, or tested until
+    /// completion; i.e., it must not be leaked (e.g., via `std::mem::forget` or a reference cycle).
+    /// Otherwise, the borrow of `data` ends while MPI may still access the buffer.
+    pub unsafe fn isend<'a, T: MpiData>(&mut self, data: &'a [T], to_rank: usize, tag: i32) -> Result<Request<'a>, StrError> {
+        let request = Request::new()?;
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::U64.n()),
-            };
+            let status = comm_isend(self.handle, request.handle, to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather usize arrays");
+                return Err("MPI failed to start the non-blocking send");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    pub fn gather_f32(&mut self, root: usize, dest: Option<&mut [f32]>, orig: &[f32]) -> Result<(), StrError> {
+    /// Starts a standard-mode non-blocking receive
+    ///
+    /// The returned request borrows `data` until the operation completes.
+    ///
+    /// `data` -- Buffer to store the received data
+    /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// # Safety
+    ///
+    /// The request (and any [crate::RequestSet] holding it) must be dropped, waited for, or tested until
+    /// completion; i.e., it must not be leaked (e.g., via `std::mem::forget` or a reference cycle).
+    /// Otherwise, the borrow of `data` ends while MPI may still write into the buffer.
+    pub unsafe fn irecv<'a, T: MpiData>(&mut self, data: &'a mut [T], from_rank: i32, tag: i32) -> Result<Request<'a>, StrError> {
+        let request = Request::new()?;
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F32.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::F32.n()),
-            };
+            let status = comm_irecv(self.handle, request.handle, to_i32(data.len()), data.as_mut_ptr() as *mut c_void, T::type_index(), from_rank, tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather f32 arrays");
+                return Err("MPI failed to start the non-blocking receive");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    pub fn gather_f64(&mut self, root: usize, dest: Option<&mut [f64]>, orig: &[f64]) -> Result<(), StrError> {
+    // persistent send/receive -------------------------------------------------------------------------
+
+    /// Creates a persistent request for a standard-mode send
+    ///
+    /// The request is bound to `data` and can be started many times (see [PersistentRequest]).
+    /// The buffer may be updated between communications via [PersistentRequest::data_mut].
+    ///
+    /// # Safety
+    ///
+    /// The request must be dropped (which waits for the active communication, if any); i.e., it must not
+    /// be leaked (e.g., via `std::mem::forget` or a reference cycle). Otherwise, the borrow of `data` ends
+    /// while MPI may still access the buffer.
+    pub unsafe fn send_init<'a, T: MpiData>(&mut self, data: &'a mut [T], to_rank: usize, tag: i32) -> Result<PersistentRequest<'a, T>, StrError> {
+        let request = PersistentRequest::new(data)?;
+        let (ptr, n) = request.buffer();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F64.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::F64.n()),
-            };
+            let status = comm_send_init(self.handle, request.ext(), n, ptr as *const c_void, T::type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather f64 arrays");
+                return Err("MPI failed to create the persistent send request");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    pub fn gather_c32(&mut self, root: usize, dest: Option<&mut [Complex32]>, orig: &[Complex32]) -> Result<(), StrError> {
+    /// Creates a persistent request for a receive
+    ///
+    /// The request is bound to `data` and can be started many times (see [PersistentRequest]).
+    /// The received data may be read between communications via [PersistentRequest::data].
+    ///
+    /// `data` -- Buffer to store the received data
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// # Safety
+    ///
+    /// The request must be dropped (which waits for the active communication, if any); i.e., it must not
+    /// be leaked (e.g., via `std::mem::forget` or a reference cycle). Otherwise, the borrow of `data` ends
+    /// while MPI may still access the buffer.
+    pub unsafe fn recv_init<'a, T: MpiData>(&mut self, data: &'a mut [T], from_rank: i32, tag: i32) -> Result<PersistentRequest<'a, T>, StrError> {
+        let request = PersistentRequest::new(data)?;
+        let (ptr, n) = request.buffer();
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C32.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::C32.n()),
-            };
+            let status = comm_recv_init(self.handle, request.ext(), n, ptr as *mut c_void, T::type_index(), from_rank, tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather Complex32 arrays");
+                return Err("MPI failed to create the persistent receive request");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    pub fn gather_c64(&mut self, root: usize, dest: Option<&mut [Complex64]>, orig: &[Complex64]) -> Result<(), StrError> {
+    // derived datatypes -----------------------------------------------------------------------------------
+
+    /// Broadcasts `count` items of a (derived) datatype from sender to all other processes in the group
+    ///
+    /// `x` -- Buffer holding the items (the layout is given by the datatype)
+    pub fn broadcast_datatype<T: MpiData>(&mut self, sender: usize, x: &mut [T], count: usize, datatype: &MpiDatatype) -> Result<(), StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(x))?;
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C64.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::C64.n()),
-            };
+            let status = comm_broadcast(self.handle, to_i32(sender), to_i32(count), x.as_mut_ptr() as *mut c_void, datatype.type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather Complex64 arrays");
+                return Err("MPI failed to broadcast array");
             }
         }
         Ok(())
     }
 
-    pub fn gather_bytes(&mut self, root: usize, dest: Option<&mut [u8]>, orig: &[u8]) -> Result<(), StrError> {
+    /// Performs a standard-mode blocking send of `count` items of a (derived) datatype
+    ///
+    /// `data` -- Buffer holding the items (the layout is given by the datatype)
+    pub fn send_datatype<T: MpiData>(&mut self, data: &[T], count: usize, datatype: &MpiDatatype, to_rank: usize, tag: i32) -> Result<(), StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(data))?;
         unsafe {
-            let status = match dest {
-                Some(d) => {
-                    let size = self.size()?;
-                    if d.len() != size * orig.len() {
-                        return Err("dest.len() must equal the number of processors times orig.len()");
-                    }
-                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::BYT.n())
-                }
-                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, MpiType::BYT.n()),
-            };
+            let status = comm_send(self.handle, to_i32(count), data.as_ptr() as *const c_void, datatype.type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather bytes arrays");
+                return Err("MPI failed to send array");
             }
         }
         Ok(())
     }
 
-    // allgather -------------------------------------------------------------------------------------------
-
-    pub fn allgather_i32(&mut self, dest: &mut [i32], orig: &[i32]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Performs a standard-mode blocking receive of (up to) `count` items of a (derived) datatype
+    ///
+    /// `data` -- Buffer to store the items (the layout is given by the datatype)
+    /// `from_rank` -- Rank from where the data was sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns the status, where `count` is the number of received items of the datatype.
+    pub fn receive_datatype<T: MpiData>(&mut self, data: &mut [T], count: usize, datatype: &MpiDatatype, from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(data))?;
+        let mut ext_status = ExtStatus::default();
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I32.n());
+            let status = comm_receive(self.handle, to_i32(count), data.as_mut_ptr() as *mut c_void, datatype.type_index(), from_rank, tag, &mut ext_status);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather i32 arrays");
+                return Err("MPI failed to receive array");
             }
         }
-        Ok(())
+        Ok(Status::from(ext_status))
     }
 
-    pub fn allgather_i64(&mut self, dest: &mut [i64], orig: &[i64]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Sends and receives `count` items of a (derived) datatype in a single (deadlock-free) operation
+    ///
+    /// `send_data` -- Buffer holding the items to be sent (the layout is given by the datatype)
+    /// `recv_data` -- Buffer to store the received items (the layout is given by the datatype)
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `recv_tag` -- Tag of the received message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns the status of the receive operation, where `count` is the number of received items of the datatype.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sendrecv_datatype<S: MpiData, R: MpiData>(&mut self, send_data: &[S], to_rank: usize, send_tag: i32, recv_data: &mut [R], from_rank: i32, recv_tag: i32, count: usize, datatype: &MpiDatatype) -> Result<Status, StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(send_data))?;
+        datatype.check_buffer(count, std::mem::size_of_val(recv_data))?;
+        let mut ext_status = ExtStatus::default();
+        let n = to_i32(count);
+        unsafe {
+            let status = comm_sendrecv(
+                self.handle,
+                n,
+                send_data.as_ptr() as *const c_void,
+                datatype.type_index(),
+                to_i32(to_rank),
+                send_tag,
+                n,
+                recv_data.as_mut_ptr() as *mut c_void,
+                datatype.type_index(),
+                from_rank,
+                recv_tag,
+                &mut ext_status,
+            );
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to send and receive arrays");
+            }
+        }
+        Ok(Status::from(ext_status))
+    }
+
+    /// Starts a standard-mode non-blocking send of `count` items of a (derived) datatype
+    ///
+    /// The returned request borrows `data` until the operation completes.
+    ///
+    /// # Safety
+    ///
+    /// The request must not be leaked (see [Communicator::isend]).
+    pub unsafe fn isend_datatype<'a, T: MpiData>(&mut self, data: &'a [T], count: usize, datatype: &MpiDatatype, to_rank: usize, tag: i32) -> Result<Request<'a>, StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(data))?;
+        let request = Request::new()?;
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::I64.n());
+            let status = comm_isend(self.handle, request.handle, to_i32(count), data.as_ptr() as *const c_void, datatype.type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather i64 arrays");
+                return Err("MPI failed to start the non-blocking send");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    #[cfg(target_pointer_width = "32")]
-    pub fn allgather_usize(&mut self, dest: &mut [usize], orig: &[usize]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Starts a standard-mode non-blocking receive of (up to) `count` items of a (derived) datatype
+    ///
+    /// The returned request borrows `data` until the operation completes.
+    ///
+    /// # Safety
+    ///
+    /// The request must not be leaked (see [Communicator::irecv]).
+    pub unsafe fn irecv_datatype<'a, T: MpiData>(&mut self, data: &'a mut [T], count: usize, datatype: &MpiDatatype, from_rank: i32, tag: i32) -> Result<Request<'a>, StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(data))?;
+        let request = Request::new()?;
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n());
+            let status = comm_irecv(self.handle, request.handle, to_i32(count), data.as_mut_ptr() as *mut c_void, datatype.type_index(), from_rank, tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather usize arrays");
+                return Err("MPI failed to start the non-blocking receive");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    #[cfg(target_pointer_width = "64")]
-    pub fn allgather_usize(&mut self, dest: &mut [usize], orig: &[usize]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Creates a persistent request for a standard-mode send of `count` items of a (derived) datatype
+    ///
+    /// # Safety
+    ///
+    /// The request must not be leaked (see [Communicator::send_init]).
+    pub unsafe fn send_init_datatype<'a, T: MpiData>(&mut self, data: &'a mut [T], count: usize, datatype: &MpiDatatype, to_rank: usize, tag: i32) -> Result<PersistentRequest<'a, T>, StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(data))?;
+        let request = PersistentRequest::new(data)?;
+        let (ptr, _) = request.buffer();
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n());
+            let status = comm_send_init(self.handle, request.ext(), to_i32(count), ptr as *const c_void, datatype.type_index(), to_i32(to_rank), tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather usize arrays");
+                return Err("MPI failed to create the persistent send request");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    pub fn allgather_u32(&mut self, dest: &mut [u32], orig: &[u32]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Creates a persistent request for a receive of (up to) `count` items of a (derived) datatype
+    ///
+    /// # Safety
+    ///
+    /// The request must not be leaked (see [Communicator::recv_init]).
+    pub unsafe fn recv_init_datatype<'a, T: MpiData>(&mut self, data: &'a mut [T], count: usize, datatype: &MpiDatatype, from_rank: i32, tag: i32) -> Result<PersistentRequest<'a, T>, StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(data))?;
+        let request = PersistentRequest::new(data)?;
+        let (ptr, _) = request.buffer();
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U32.n());
+            let status = comm_recv_init(self.handle, request.ext(), to_i32(count), ptr as *mut c_void, datatype.type_index(), from_rank, tag);
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather u32 arrays");
+                return Err("MPI failed to create the persistent receive request");
             }
         }
-        Ok(())
+        Ok(request)
     }
 
-    pub fn allgather_u64(&mut self, dest: &mut [u64], orig: &[u64]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Gathers `count` items of a (derived) datatype from all processes to the root process
+    ///
+    /// `dest` -- Buffer to store `count * size` items (root only; must be None on the other processes)
+    /// `orig` -- Buffer holding the `count` items sent by this process
+    pub fn gather_datatype<T: MpiData>(&mut self, root: usize, dest: Option<&mut [T]>, orig: &[T], count: usize, datatype: &MpiDatatype) -> Result<(), StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(orig))?;
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::U64.n());
+            let status = match dest {
+                Some(d) => {
+                    datatype.check_buffer(count * self.size()?, std::mem::size_of_val(d))?;
+                    comm_gather_im_root(self.handle, to_i32(root), to_i32(count), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, datatype.type_index())
+                }
+                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(count), orig.as_ptr() as *const c_void, datatype.type_index()),
+            };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather u64 arrays");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    pub fn allgather_f32(&mut self, dest: &mut [f32], orig: &[f32]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Gathers `count` items of a (derived) datatype from all processes and distributes the result to all processes
+    ///
+    /// `dest` -- Buffer to store `count * size` items
+    /// `orig` -- Buffer holding the `count` items sent by this process
+    pub fn allgather_datatype<T: MpiData>(&mut self, dest: &mut [T], orig: &[T], count: usize, datatype: &MpiDatatype) -> Result<(), StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(orig))?;
+        datatype.check_buffer(count * self.size()?, std::mem::size_of_val(dest))?;
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F32.n());
+            let status = comm_allgather(self.handle, to_i32(count), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, datatype.type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather f32 arrays");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    pub fn allgather_f64(&mut self, dest: &mut [f64], orig: &[f64]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    /// Scatters `count` items of a (derived) datatype from the root process to all processes
+    ///
+    /// `dest` -- Buffer to store the `count` items received by this process
+    /// `orig` -- Buffer holding `count * size` items (root only; must be None on the other processes)
+    pub fn scatter_datatype<T: MpiData>(&mut self, root: usize, dest: &mut [T], orig: Option<&[T]>, count: usize, datatype: &MpiDatatype) -> Result<(), StrError> {
+        datatype.check_buffer(count, std::mem::size_of_val(dest))?;
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::F64.n());
+            let status = match orig {
+                Some(o) => {
+                    datatype.check_buffer(count * self.size()?, std::mem::size_of_val(o))?;
+                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(count), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, datatype.type_index())
+                }
+                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(count), dest.as_mut_ptr() as *mut c_void, datatype.type_index()),
+            };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather f64 arrays");
+                return Err("MPI failed to scatter array");
             }
         }
         Ok(())
     }
 
-    pub fn allgather_c32(&mut self, dest: &mut [Complex32], orig: &[Complex32]) -> Result<(), StrError> {
-        let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
+    // gather -------------------------------------------------------------------------------------------
+
+    /// Gathers values from all processes to the root process
+    ///
+    /// `dest` -- Buffer to store the gathered data (root only; must be None on the other processes)
+    /// `orig` -- Data to be sent by this process
+    pub fn gather<T: MpiData>(&mut self, root: usize, dest: Option<&mut [T]>, orig: &[T]) -> Result<(), StrError> {
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C32.n());
+            let status = match dest {
+                Some(d) => {
+                    let size = self.size()?;
+                    if d.len() != size * orig.len() {
+                        return Err("dest.len() must equal the number of processors times orig.len()");
+                    }
+                    comm_gather_im_root(self.handle, to_i32(root), to_i32(orig.len()), d.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index())
+                }
+                None => comm_gather_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, T::type_index()),
+            };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather Complex32 arrays");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    pub fn allgather_c64(&mut self, dest: &mut [Complex64], orig: &[Complex64]) -> Result<(), StrError> {
+    pub fn gather_i32(&mut self, root: usize, dest: Option<&mut [i32]>, orig: &[i32]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_i64(&mut self, root: usize, dest: Option<&mut [i64]>, orig: &[i64]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_u32(&mut self, root: usize, dest: Option<&mut [u32]>, orig: &[u32]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_u64(&mut self, root: usize, dest: Option<&mut [u64]>, orig: &[u64]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_usize(&mut self, root: usize, dest: Option<&mut [usize]>, orig: &[usize]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_f32(&mut self, root: usize, dest: Option<&mut [f32]>, orig: &[f32]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_f64(&mut self, root: usize, dest: Option<&mut [f64]>, orig: &[f64]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_c32(&mut self, root: usize, dest: Option<&mut [Complex32]>, orig: &[Complex32]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_c64(&mut self, root: usize, dest: Option<&mut [Complex64]>, orig: &[Complex64]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    pub fn gather_bytes(&mut self, root: usize, dest: Option<&mut [u8]>, orig: &[u8]) -> Result<(), StrError> {
+        self.gather(root, dest, orig)
+    }
+
+    /// Gathers values from all processes to the root process (in place)
+    ///
+    /// `data` -- On the root process, the buffer to store the gathered data, whose length must be
+    /// a multiple of the number of processors; the block of the root process must already be in
+    /// its position. On the other processes, the data to be sent.
+    pub fn gather_in_place<T: MpiData>(&mut self, root: usize, data: &mut [T]) -> Result<(), StrError> {
+        let rank = self.rank()?;
         let size = self.size()?;
-        if dest.len() != size * orig.len() {
-            return Err("dest.len() must equal the number of processors times orig.len()");
-        }
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::C64.n());
+            let status = if rank == root {
+                if data.len() % size != 0 {
+                    return Err("data.len() must be a multiple of the number of processors");
+                }
+                comm_gather_in_place_im_root(self.handle, to_i32(root), to_i32(data.len() / size), data.as_mut_ptr() as *mut c_void, T::type_index())
+            } else {
+                comm_gather_im_not_root(self.handle, to_i32(root), to_i32(data.len()), data.as_ptr() as *const c_void, T::type_index())
+            };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather Complex64 arrays");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    pub fn allgather_bytes(&mut self, dest: &mut [u8], orig: &[u8]) -> Result<(), StrError> {
+    // allgather -------------------------------------------------------------------------------------------
+
+    /// Gathers values from all processes and distributes the result to all processes
+    pub fn allgather<T: MpiData>(&mut self, dest: &mut [T], orig: &[T]) -> Result<(), StrError> {
         let size = self.size()?;
         if dest.len() != size * orig.len() {
             return Err("dest.len() must equal the number of processors times orig.len()");
         }
         unsafe {
-            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, MpiType::BYT.n());
+            let status = comm_allgather(self.handle, to_i32(orig.len()), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to gather bytes arrays");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    // scatter -------------------------------------------------------------------------------------------
+    pub fn allgather_i32(&mut self, dest: &mut [i32], orig: &[i32]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
 
-    pub fn scatter_i32(&mut self, root: usize, dest: &mut [i32], orig: Option<&[i32]>) -> Result<(), StrError> {
-        unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::I32.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::I32.n()),
-            };
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter i32 array");
-            }
-        }
-        Ok(())
+    pub fn allgather_i64(&mut self, dest: &mut [i64], orig: &[i64]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
     }
 
-    pub fn scatter_i64(&mut self, root: usize, dest: &mut [i64], orig: Option<&[i64]>) -> Result<(), StrError> {
+    pub fn allgather_u32(&mut self, dest: &mut [u32], orig: &[u32]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_u64(&mut self, dest: &mut [u64], orig: &[u64]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_usize(&mut self, dest: &mut [usize], orig: &[usize]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_f32(&mut self, dest: &mut [f32], orig: &[f32]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_f64(&mut self, dest: &mut [f64], orig: &[f64]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_c32(&mut self, dest: &mut [Complex32], orig: &[Complex32]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_c64(&mut self, dest: &mut [Complex64], orig: &[Complex64]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    pub fn allgather_bytes(&mut self, dest: &mut [u8], orig: &[u8]) -> Result<(), StrError> {
+        self.allgather(dest, orig)
+    }
+
+    /// Gathers values from all processes and distributes the result to all processes (in place)
+    ///
+    /// `data` -- Buffer to store the gathered data, whose length must be a multiple of the number
+    /// of processors; the block of this process must already be in its position.
+    pub fn allgather_in_place<T: MpiData>(&mut self, data: &mut [T]) -> Result<(), StrError> {
+        let size = self.size()?;
+        if data.len() % size != 0 {
+            return Err("data.len() must be a multiple of the number of processors");
+        }
         unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::I64.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::I64.n()),
-            };
+            let status = comm_allgather_in_place(self.handle, to_i32(data.len() / size), data.as_mut_ptr() as *mut c_void, T::type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter i64 array");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    pub fn scatter_u32(&mut self, root: usize, dest: &mut [u32], orig: Option<&[u32]>) -> Result<(), StrError> {
+    // scatter -------------------------------------------------------------------------------------------
+
+    /// Scatters values from the root process to all processes
+    ///
+    /// `dest` -- Buffer to store the data received by this process
+    /// `orig` -- Data to be scattered (root only; must be None on the other processes)
+    pub fn scatter<T: MpiData>(&mut self, root: usize, dest: &mut [T], orig: Option<&[T]>) -> Result<(), StrError> {
         unsafe {
             let status = match orig {
                 Some(o) => {
@@ -1367,177 +1782,610 @@ impl Communicator {
                     if o.len() != size * dest.len() {
                         return Err("orig.len() must equal the number of processors times dest.len()");
                     }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::U32.n())
+                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, T::type_index())
                 }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::U32.n()),
+                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, T::type_index()),
             };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter u32 array");
+                return Err("MPI failed to scatter array");
             }
         }
         Ok(())
     }
 
+    pub fn scatter_i32(&mut self, root: usize, dest: &mut [i32], orig: Option<&[i32]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_i64(&mut self, root: usize, dest: &mut [i64], orig: Option<&[i64]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_u32(&mut self, root: usize, dest: &mut [u32], orig: Option<&[u32]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
     pub fn scatter_u64(&mut self, root: usize, dest: &mut [u64], orig: Option<&[u64]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_usize(&mut self, root: usize, dest: &mut [usize], orig: Option<&[usize]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_f32(&mut self, root: usize, dest: &mut [f32], orig: Option<&[f32]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_f64(&mut self, root: usize, dest: &mut [f64], orig: Option<&[f64]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_c32(&mut self, root: usize, dest: &mut [Complex32], orig: Option<&[Complex32]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_c64(&mut self, root: usize, dest: &mut [Complex64], orig: Option<&[Complex64]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    pub fn scatter_bytes(&mut self, root: usize, dest: &mut [u8], orig: Option<&[u8]>) -> Result<(), StrError> {
+        self.scatter(root, dest, orig)
+    }
+
+    // gatherv ------------------------------------------------------------------------------------------
+
+    /// Gathers varying amounts of data from all processes to the root process
+    ///
+    /// `dest` -- Buffer to store the gathered data (root only; must be None on the other processes)
+    /// `counts` -- Number of elements received from each process (root only; ignored on the other processes)
+    /// `displs` -- Position in `dest` of the data received from each process (root only; ignored on the other processes)
+    /// `orig` -- Data to be sent by this process
+    pub fn gatherv<T: MpiData>(&mut self, root: usize, dest: Option<&mut [T]>, counts: &[usize], displs: &[usize], orig: &[T]) -> Result<(), StrError> {
+        unsafe { self.gatherv_raw(root, dest.map(|d| (d.as_mut_ptr(), d.len())), counts, displs, orig) }
+    }
+
+    /// Implements [Communicator::gatherv] with `dest` given by a pointer and length (the memory may be uninitialized)
+    unsafe fn gatherv_raw<T: MpiData>(&mut self, root: usize, dest: Option<(*mut T, usize)>, counts: &[usize], displs: &[usize], orig: &[T]) -> Result<(), StrError> {
         unsafe {
-            let status = match orig {
-                Some(o) => {
+            let status = match dest {
+                Some((ptr, len)) => {
                     let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::U64.n())
+                    check_counts_and_displs(size, counts, displs, len)?;
+                    let (c, p) = (to_i32_vec(counts), to_i32_vec(displs));
+                    comm_gatherv_im_root(self.handle, to_i32(root), to_i32(orig.len()), ptr as *mut c_void, c.as_ptr(), p.as_ptr(), orig.as_ptr() as *const c_void, T::type_index())
                 }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::U64.n()),
+                None => comm_gatherv_im_not_root(self.handle, to_i32(root), to_i32(orig.len()), orig.as_ptr() as *const c_void, T::type_index()),
             };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter u64 array");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    #[cfg(target_pointer_width = "32")]
-    pub fn scatter_usize(&mut self, root: usize, dest: &mut [usize], orig: Option<&[usize]>) -> Result<(), StrError> {
+    /// Gathers arrays of any length from all processes to the root process (packed in sequence)
+    ///
+    /// The lengths are gathered first; thus, the processes may send arrays of different lengths.
+    ///
+    /// Returns `(data, offsets)`, where the array received from process `i` is `data[offsets[i]..offsets[i+1]]`.
+    /// On the processes other than root, both `data` and `offsets` are empty.
+    pub fn gather_flat<T: MpiData>(&mut self, root: usize, orig: &[T]) -> Result<(Vec<T>, Vec<usize>), StrError> {
+        let rank = self.rank()?;
+        let size = self.size()?;
+        let len = [orig.len()];
+        if rank != root {
+            self.gather(root, None, &len)?;
+            self.gatherv(root, None, &[], &[], orig)?;
+            return Ok((Vec::new(), Vec::new()));
+        }
+        let mut counts = vec![0; size];
+        self.gather(root, Some(&mut counts), &len)?;
+        let offsets = counts_to_offsets(&counts);
+        let n = offsets[size];
+        let mut data: Vec<T> = Vec::with_capacity(n);
         unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::U32.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::U32.n()),
-            };
+            self.gatherv_raw(root, Some((data.as_mut_ptr(), n)), &counts, &offsets[..size], orig)?;
+            data.set_len(n);
+        }
+        Ok((data, offsets))
+    }
+
+    /// Gathers arrays of any length from all processes to the root process
+    ///
+    /// The lengths are gathered first; thus, the processes may send arrays of different lengths.
+    ///
+    /// Returns the arrays received from each process on the root process (None on the other processes).
+    pub fn gather_vec<T: MpiData>(&mut self, root: usize, orig: &[T]) -> Result<Option<Vec<Vec<T>>>, StrError> {
+        let (data, offsets) = self.gather_flat(root, orig)?;
+        if offsets.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(split_by_offsets(&data, &offsets)))
+    }
+
+    // allgatherv ---------------------------------------------------------------------------------------
+
+    /// Gathers varying amounts of data from all processes and distributes the result to all processes
+    ///
+    /// `dest` -- Buffer to store the gathered data
+    /// `counts` -- Number of elements received from each process
+    /// `displs` -- Position in `dest` of the data received from each process
+    /// `orig` -- Data to be sent by this process (the length must equal `counts[rank]`)
+    pub fn allgatherv<T: MpiData>(&mut self, dest: &mut [T], counts: &[usize], displs: &[usize], orig: &[T]) -> Result<(), StrError> {
+        unsafe { self.allgatherv_raw(dest.as_mut_ptr(), dest.len(), counts, displs, orig) }
+    }
+
+    /// Implements [Communicator::allgatherv] with `dest` given by a pointer and length (the memory may be uninitialized)
+    unsafe fn allgatherv_raw<T: MpiData>(&mut self, dest: *mut T, len: usize, counts: &[usize], displs: &[usize], orig: &[T]) -> Result<(), StrError> {
+        let rank = self.rank()?;
+        let size = self.size()?;
+        check_counts_and_displs(size, counts, displs, len)?;
+        if orig.len() != counts[rank] {
+            return Err("orig.len() must equal counts[rank]");
+        }
+        let (c, p) = (to_i32_vec(counts), to_i32_vec(displs));
+        unsafe {
+            let status = comm_allgatherv(self.handle, to_i32(orig.len()), dest as *mut c_void, c.as_ptr(), p.as_ptr(), orig.as_ptr() as *const c_void, T::type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter usize array");
+                return Err("MPI failed to gather arrays");
             }
         }
         Ok(())
     }
 
-    #[cfg(target_pointer_width = "64")]
-    pub fn scatter_usize(&mut self, root: usize, dest: &mut [usize], orig: Option<&[usize]>) -> Result<(), StrError> {
+    /// Gathers arrays of any length from all processes and distributes the result to all processes (packed in sequence)
+    ///
+    /// The lengths are gathered first; thus, the processes may send arrays of different lengths.
+    ///
+    /// Returns `(data, offsets)`, where the array received from process `i` is `data[offsets[i]..offsets[i+1]]`.
+    pub fn allgather_flat<T: MpiData>(&mut self, orig: &[T]) -> Result<(Vec<T>, Vec<usize>), StrError> {
+        let size = self.size()?;
+        let mut counts = vec![0; size];
+        self.allgather(&mut counts, &[orig.len()])?;
+        let offsets = counts_to_offsets(&counts);
+        let n = offsets[size];
+        let mut data: Vec<T> = Vec::with_capacity(n);
+        unsafe {
+            self.allgatherv_raw(data.as_mut_ptr(), n, &counts, &offsets[..size], orig)?;
+            data.set_len(n);
+        }
+        Ok((data, offsets))
+    }
+
+    /// Gathers arrays of any length from all processes and distributes the result to all processes
+    ///
+    /// The lengths are gathered first; thus, the processes may send arrays of different lengths.
+    ///
+    /// Returns the arrays received from each process.
+    pub fn allgather_vec<T: MpiData>(&mut self, orig: &[T]) -> Result<Vec<Vec<T>>, StrError> {
+        let (data, offsets) = self.allgather_flat(orig)?;
+        Ok(split_by_offsets(&data, &offsets))
+    }
+
+    // scatterv -----------------------------------------------------------------------------------------
+
+    /// Scatters varying amounts of data from the root process to all processes
+    ///
+    /// `dest` -- Buffer to store the data received by this process
+    /// `orig` -- Data to be scattered (root only; must be None on the other processes)
+    /// `counts` -- Number of elements sent to each process (root only; ignored on the other processes)
+    /// `displs` -- Position in `orig` of the data sent to each process (root only; ignored on the other processes)
+    pub fn scatterv<T: MpiData>(&mut self, root: usize, dest: &mut [T], orig: Option<&[T]>, counts: &[usize], displs: &[usize]) -> Result<(), StrError> {
+        unsafe { self.scatterv_raw(root, dest.as_mut_ptr(), dest.len(), orig, counts, displs) }
+    }
+
+    /// Implements [Communicator::scatterv] with `dest` given by a pointer and length (the memory may be uninitialized)
+    unsafe fn scatterv_raw<T: MpiData>(&mut self, root: usize, dest: *mut T, len: usize, orig: Option<&[T]>, counts: &[usize], displs: &[usize]) -> Result<(), StrError> {
         unsafe {
             let status = match orig {
                 Some(o) => {
                     let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::U64.n())
+                    check_counts_and_displs(size, counts, displs, o.len())?;
+                    let (c, p) = (to_i32_vec(counts), to_i32_vec(displs));
+                    comm_scatterv_im_root(self.handle, to_i32(root), to_i32(len), dest as *mut c_void, c.as_ptr(), p.as_ptr(), o.as_ptr() as *const c_void, T::type_index())
                 }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::U64.n()),
+                None => comm_scatterv_im_not_root(self.handle, to_i32(root), to_i32(len), dest as *mut c_void, T::type_index()),
             };
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter usize array");
+                return Err("MPI failed to scatter array");
             }
         }
         Ok(())
     }
 
-    pub fn scatter_f32(&mut self, root: usize, dest: &mut [f32], orig: Option<&[f32]>) -> Result<(), StrError> {
-        unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::F32.n())
+    /// Scatters arrays of any length from the root process to all processes
+    ///
+    /// The lengths are scattered first; thus, the processes may receive arrays of different lengths.
+    ///
+    /// `orig` -- The arrays to be sent to each process (root only; must be None on the other processes)
+    ///
+    /// Returns the array received by this process.
+    pub fn scatter_vec<T: MpiData>(&mut self, root: usize, orig: Option<&[Vec<T>]>) -> Result<Vec<T>, StrError> {
+        let mut len = [0];
+        let packed = match orig {
+            Some(o) => {
+                let size = self.size()?;
+                if o.len() != size {
+                    return Err("orig.len() must equal the number of processors");
                 }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::F32.n()),
-            };
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter f32 array");
+                let counts: Vec<_> = o.iter().map(|v| v.len()).collect();
+                self.scatter(root, &mut len, Some(&counts))?;
+                Some((o.concat(), counts))
+            }
+            None => {
+                self.scatter(root, &mut len, None)?;
+                None
+            }
+        };
+        let n = len[0];
+        let mut dest: Vec<T> = Vec::with_capacity(n);
+        unsafe {
+            match &packed {
+                Some((data, counts)) => {
+                    let offsets = counts_to_offsets(counts);
+                    self.scatterv_raw(root, dest.as_mut_ptr(), n, Some(data), counts, &offsets[..counts.len()])?
+                }
+                None => self.scatterv_raw(root, dest.as_mut_ptr(), n, None, &[], &[])?,
             }
+            dest.set_len(n);
         }
-        Ok(())
+        Ok(dest)
     }
 
-    pub fn scatter_f64(&mut self, root: usize, dest: &mut [f64], orig: Option<&[f64]>) -> Result<(), StrError> {
+    // alltoall -----------------------------------------------------------------------------------------
+
+    /// Sends distinct data from all processes to all processes
+    ///
+    /// The i-th block of `orig` is sent to process i and the block received from process j
+    /// is stored in the j-th block of `dest`; all blocks have the same length.
+    ///
+    /// `dest` -- Buffer to store the received data (the length must equal `orig.len()`)
+    /// `orig` -- Data to be sent (the length must be a multiple of the number of processors)
+    pub fn alltoall<T: MpiData>(&mut self, dest: &mut [T], orig: &[T]) -> Result<(), StrError> {
+        let size = self.size()?;
+        if orig.len() % size != 0 {
+            return Err("orig.len() must be a multiple of the number of processors");
+        }
+        if dest.len() != orig.len() {
+            return Err("dest.len() must equal orig.len()");
+        }
         unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::F64.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::F64.n()),
-            };
+            let status = comm_alltoall(self.handle, to_i32(orig.len() / size), dest.as_mut_ptr() as *mut c_void, orig.as_ptr() as *const c_void, T::type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter f64 array");
+                return Err("MPI failed to exchange arrays");
             }
         }
         Ok(())
     }
 
-    pub fn scatter_c32(&mut self, root: usize, dest: &mut [Complex32], orig: Option<&[Complex32]>) -> Result<(), StrError> {
+    /// Sends varying amounts of distinct data from all processes to all processes
+    ///
+    /// `dest` -- Buffer to store the received data
+    /// `recv_counts` -- Number of elements received from each process
+    /// `recv_displs` -- Position in `dest` of the data received from each process
+    /// `orig` -- Data to be sent
+    /// `send_counts` -- Number of elements sent to each process
+    /// `send_displs` -- Position in `orig` of the data sent to each process
+    pub fn alltoallv<T: MpiData>(&mut self, dest: &mut [T], recv_counts: &[usize], recv_displs: &[usize], orig: &[T], send_counts: &[usize], send_displs: &[usize]) -> Result<(), StrError> {
+        unsafe { self.alltoallv_raw((dest.as_mut_ptr(), dest.len()), recv_counts, recv_displs, orig, send_counts, send_displs) }
+    }
+
+    /// Implements [Communicator::alltoallv] with `dest` given by a pointer and length (the memory may be uninitialized)
+    unsafe fn alltoallv_raw<T: MpiData>(&mut self, (dest, len): (*mut T, usize), recv_counts: &[usize], recv_displs: &[usize], orig: &[T], send_counts: &[usize], send_displs: &[usize]) -> Result<(), StrError> {
+        let size = self.size()?;
+        check_counts_and_displs(size, recv_counts, recv_displs, len)?;
+        check_counts_and_displs(size, send_counts, send_displs, orig.len())?;
+        let (rc, rp) = (to_i32_vec(recv_counts), to_i32_vec(recv_displs));
+        let (sc, sp) = (to_i32_vec(send_counts), to_i32_vec(send_displs));
         unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::C32.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::C32.n()),
-            };
+            let status = comm_alltoallv(self.handle, dest as *mut c_void, rc.as_ptr(), rp.as_ptr(), orig.as_ptr() as *const c_void, sc.as_ptr(), sp.as_ptr(), T::type_index());
             if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter Complex32 array");
+                return Err("MPI failed to exchange arrays");
             }
         }
         Ok(())
     }
 
-    pub fn scatter_c64(&mut self, root: usize, dest: &mut [Complex64], orig: Option<&[Complex64]>) -> Result<(), StrError> {
+    /// Sends varying amounts of distinct data from all processes to all processes (packed in sequence)
+    ///
+    /// The send counts are exchanged first; thus, the receive counts do not need to be known in advance.
+    ///
+    /// `orig` -- Data to be sent; the part sent to process `i` follows the part sent to process `i-1`
+    /// `send_counts` -- Number of elements sent to each process
+    ///
+    /// Returns `(data, offsets)`, where the array received from process `i` is `data[offsets[i]..offsets[i+1]]`.
+    pub fn alltoall_flat<T: MpiData>(&mut self, orig: &[T], send_counts: &[usize]) -> Result<(Vec<T>, Vec<usize>), StrError> {
+        let size = self.size()?;
+        if send_counts.len() != size {
+            return Err("send_counts.len() must equal the number of processors");
+        }
+        let send_offsets = counts_to_offsets(send_counts);
+        if send_offsets[size] != orig.len() {
+            return Err("orig.len() must equal the sum of send_counts");
+        }
+        let mut recv_counts = vec![0; size];
+        self.alltoall(&mut recv_counts, send_counts)?;
+        let offsets = counts_to_offsets(&recv_counts);
+        let n = offsets[size];
+        let mut data: Vec<T> = Vec::with_capacity(n);
         unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::C64.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::C64.n()),
-            };
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter Complex64 array");
+            self.alltoallv_raw((data.as_mut_ptr(), n), &recv_counts, &offsets[..size], orig, send_counts, &send_offsets[..size])?;
+            data.set_len(n);
+        }
+        Ok((data, offsets))
+    }
+
+    /// Sends arrays of any length from all processes to all processes
+    ///
+    /// The lengths are exchanged first; thus, the processes may send arrays of different lengths.
+    ///
+    /// `orig` -- The arrays to be sent to each process
+    ///
+    /// Returns the arrays received from each process.
+    pub fn alltoall_vec<T: MpiData>(&mut self, orig: &[Vec<T>]) -> Result<Vec<Vec<T>>, StrError> {
+        if orig.len() != self.size()? {
+            return Err("orig.len() must equal the number of processors");
+        }
+        let send_counts: Vec<_> = orig.iter().map(|v| v.len()).collect();
+        let (data, offsets) = self.alltoall_flat(&orig.concat(), &send_counts)?;
+        Ok(split_by_offsets(&data, &offsets))
+    }
+
+    // bools and chars ----------------------------------------------------------------------------------
+
+    /// Broadcasts booleans from sender to all other processes in the group (sent as MPI_C_BOOL)
+    pub fn broadcast_bools(&mut self, sender: usize, x: &mut [bool]) -> Result<(), StrError> {
+        let mut y = to_mpi_bools(x);
+        self.broadcast(sender, &mut y)?;
+        from_mpi_bools(x, &y);
+        Ok(())
+    }
+
+    /// Reduces booleans from all processes to the root process with a logical operator
+    pub fn reduce_bools(&mut self, root: usize, dest: &mut [bool], orig: &[bool], op: MpiOpBool) -> Result<(), StrError> {
+        let mut y = to_mpi_bools(dest);
+        self.reduce(root, &mut y, &to_mpi_bools(orig), op)?;
+        from_mpi_bools(dest, &y);
+        Ok(())
+    }
+
+    /// Combines booleans from all processes with a logical operator and distributes the result
+    pub fn allreduce_bools(&mut self, dest: &mut [bool], orig: &[bool], op: MpiOpBool) -> Result<(), StrError> {
+        let mut y = to_mpi_bools(dest);
+        self.allreduce(&mut y, &to_mpi_bools(orig), op)?;
+        from_mpi_bools(dest, &y);
+        Ok(())
+    }
+
+    /// Sends booleans to another process (sent as MPI_C_BOOL; see [Communicator::receive_bools])
+    pub fn send_bools(&mut self, data: &[bool], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(&to_mpi_bools(data), to_rank, tag)
+    }
+
+    /// Receives booleans sent by another process (any non-zero byte is converted to true)
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    pub fn receive_bools(&mut self, data: &mut [bool], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        let mut y = vec![MpiBool::default(); data.len()];
+        let status = self.receive(&mut y, from_rank, tag)?;
+        from_mpi_bools(data, &y);
+        Ok(status)
+    }
+
+    /// Gathers booleans from all processes to the root process
+    ///
+    /// `dest` -- Buffer to store the gathered data (root only; must be None on the other processes)
+    pub fn gather_bools(&mut self, root: usize, dest: Option<&mut [bool]>, orig: &[bool]) -> Result<(), StrError> {
+        match dest {
+            Some(d) => {
+                let mut y = vec![MpiBool::default(); d.len()];
+                self.gather(root, Some(&mut y), &to_mpi_bools(orig))?;
+                from_mpi_bools(d, &y);
+                Ok(())
             }
+            None => self.gather(root, None, &to_mpi_bools(orig)),
+        }
+    }
+
+    /// Scatters booleans from the root process to all processes
+    ///
+    /// `orig` -- Data to be scattered (root only; must be None on the other processes)
+    pub fn scatter_bools(&mut self, root: usize, dest: &mut [bool], orig: Option<&[bool]>) -> Result<(), StrError> {
+        let mut y = vec![MpiBool::default(); dest.len()];
+        match orig {
+            Some(o) => self.scatter(root, &mut y, Some(&to_mpi_bools(o)))?,
+            None => self.scatter(root, &mut y, None)?,
         }
+        from_mpi_bools(dest, &y);
         Ok(())
     }
 
-    pub fn scatter_bytes(&mut self, root: usize, dest: &mut [u8], orig: Option<&[u8]>) -> Result<(), StrError> {
-        unsafe {
-            let status = match orig {
-                Some(o) => {
-                    let size = self.size()?;
-                    if o.len() != size * dest.len() {
-                        return Err("orig.len() must equal the number of processors times dest.len()");
-                    }
-                    comm_scatter_im_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_mut_ptr() as *mut c_void, o.as_ptr() as *const c_void, MpiType::BYT.n())
-                }
-                None => comm_scatter_im_not_root(self.handle, to_i32(root), to_i32(dest.len()), dest.as_ptr() as *mut c_void, MpiType::BYT.n()),
-            };
-            if status != C_MPI_SUCCESS {
-                return Err("MPI failed to scatter bytes array");
+    /// Broadcasts chars from sender to all other processes in the group (sent as u32)
+    ///
+    /// Returns an error if the received values are not valid chars.
+    pub fn broadcast_chars(&mut self, sender: usize, x: &mut [char]) -> Result<(), StrError> {
+        let mut y: Vec<u32> = x.iter().map(|&c| c as u32).collect();
+        self.broadcast(sender, &mut y)?;
+        from_u32s(x, &y)
+    }
+
+    /// Sends chars to another process (sent as u32; see [Communicator::receive_chars])
+    pub fn send_chars(&mut self, data: &[char], to_rank: usize, tag: i32) -> Result<(), StrError> {
+        let y: Vec<u32> = data.iter().map(|&c| c as u32).collect();
+        self.send(&y, to_rank, tag)
+    }
+
+    /// Receives chars sent by another process
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns an error if the received values are not valid chars.
+    pub fn receive_chars(&mut self, data: &mut [char], from_rank: i32, tag: i32) -> Result<Status, StrError> {
+        let mut y = vec![0_u32; data.len()];
+        let status = self.receive(&mut y, from_rank, tag)?;
+        from_u32s(data, &y)?;
+        Ok(status)
+    }
+
+    /// Gathers chars from all processes to the root process
+    ///
+    /// `dest` -- Buffer to store the gathered data (root only; must be None on the other processes)
+    ///
+    /// Returns an error if the received values are not valid chars.
+    pub fn gather_chars(&mut self, root: usize, dest: Option<&mut [char]>, orig: &[char]) -> Result<(), StrError> {
+        let x: Vec<u32> = orig.iter().map(|&c| c as u32).collect();
+        match dest {
+            Some(d) => {
+                let mut y = vec![0_u32; d.len()];
+                self.gather(root, Some(&mut y), &x)?;
+                from_u32s(d, &y)
             }
+            None => self.gather(root, None, &x),
+        }
+    }
+
+    /// Scatters chars from the root process to all processes
+    ///
+    /// `orig` -- Data to be scattered (root only; must be None on the other processes)
+    ///
+    /// Returns an error if the received values are not valid chars.
+    pub fn scatter_chars(&mut self, root: usize, dest: &mut [char], orig: Option<&[char]>) -> Result<(), StrError> {
+        let mut y = vec![0_u32; dest.len()];
+        match orig {
+            Some(o) => {
+                let x: Vec<u32> = o.iter().map(|&c| c as u32).collect();
+                self.scatter(root, &mut y, Some(&x))?
+            }
+            None => self.scatter(root, &mut y, None)?,
+        }
+        from_u32s(dest, &y)
+    }
+
+    // strings ------------------------------------------------------------------------------------------
+
+    /// Broadcasts a string from sender to all other processes in the group
+    ///
+    /// The number of bytes is broadcast first; thus, the string is never truncated.
+    ///
+    /// `string` -- The string to be sent (sender) or overwritten (other processes)
+    pub fn broadcast_string(&mut self, sender: usize, string: &mut String) -> Result<(), StrError> {
+        if self.rank()? == sender {
+            self.broadcast(sender, &mut [string.len()])?;
+            let mut bytes = string.as_bytes().to_vec();
+            self.broadcast(sender, &mut bytes)?;
+        } else {
+            let mut len = [0_usize];
+            self.broadcast(sender, &mut len)?;
+            let mut bytes = vec![0_u8; len[0]];
+            self.broadcast(sender, &mut bytes)?;
+            *string = to_utf8(bytes)?;
         }
         Ok(())
     }
+
+    /// Sends a string to another process (see [Communicator::receive_string])
+    pub fn send_string(&mut self, string: &str, to_rank: usize, tag: i32) -> Result<(), StrError> {
+        self.send(string.as_bytes(), to_rank, tag)
+    }
+
+    /// Receives a string of any length sent by another process
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns the string and the status with `count` given in number of bytes.
+    pub fn receive_string(&mut self, from_rank: i32, tag: i32) -> Result<(String, Status), StrError> {
+        let (bytes, status) = self.receive_vec::<u8>(from_rank, tag)?;
+        Ok((to_utf8(bytes)?, status))
+    }
+
+    /// Gathers strings of any length from all processes to the root process
+    ///
+    /// Returns the strings received from each process on the root process (None on the other processes).
+    pub fn gather_strings(&mut self, root: usize, string: &str) -> Result<Option<Vec<String>>, StrError> {
+        match self.gather_vec(root, string.as_bytes())? {
+            Some(all) => Ok(Some(all.into_iter().map(to_utf8).collect::<Result<_, _>>()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Gathers strings of any length from all processes and distributes the result to all processes
+    ///
+    /// Returns the strings received from each process.
+    pub fn allgather_strings(&mut self, string: &str) -> Result<Vec<String>, StrError> {
+        self.allgather_vec(string.as_bytes())?.into_iter().map(to_utf8).collect()
+    }
+}
+
+/// Converts booleans to bytes with the memory layout of MPI_C_BOOL
+fn to_mpi_bools(x: &[bool]) -> Vec<MpiBool> {
+    x.iter().map(|&b| MpiBool(b as u8)).collect()
+}
+
+/// Converts bytes with the memory layout of MPI_C_BOOL to booleans (any non-zero byte is true)
+fn from_mpi_bools(dest: &mut [bool], x: &[MpiBool]) {
+    for (d, b) in dest.iter_mut().zip(x) {
+        *d = b.0 != 0;
+    }
+}
+
+/// Converts the received u32 values to chars (the values must be valid Unicode scalar values)
+fn from_u32s(dest: &mut [char], x: &[u32]) -> Result<(), StrError> {
+    for (d, &v) in dest.iter_mut().zip(x) {
+        *d = char::from_u32(v).ok_or("the received data contains an invalid char")?;
+    }
+    Ok(())
+}
+
+/// Converts the received bytes to a string (without trimming or replacing any character)
+fn to_utf8(bytes: Vec<u8>) -> Result<String, StrError> {
+    String::from_utf8(bytes).map_err(|_| "cannot convert bytes to UTF-8 string")
+}
+
+/// Reserves space for n elements in the vector given by context and returns its buffer (see [Communicator::receive_vec])
+extern "C" fn allocate_vec<T: MpiData>(context: *mut c_void, n: i32) -> *mut c_void {
+    let data = unsafe { &mut *(context as *mut Vec<T>) };
+    data.reserve_exact(n.max(0) as usize);
+    data.as_mut_ptr() as *mut c_void
+}
+
+/// Checks the counts and displacements of the variable-count functions
+fn check_counts_and_displs(size: usize, counts: &[usize], displs: &[usize], len: usize) -> Result<(), StrError> {
+    if counts.len() != size || displs.len() != size {
+        return Err("counts.len() and displs.len() must equal the number of processors");
+    }
+    if counts.iter().zip(displs).any(|(c, d)| c + d > len) {
+        return Err("the buffer is too small for the given counts and displacements");
+    }
+    Ok(())
+}
+
+/// Converts (value, index) tuples to pairs with the memory layout of the MPI pair datatypes
+fn to_pairs<T: MpiLocReducible>(tuples: &[(T, usize)]) -> Vec<MpiPair<T>> {
+    tuples.iter().map(|&(value, index)| MpiPair { value, index: to_i32(index) }).collect()
+}
+
+/// Converts pairs with the memory layout of the MPI pair datatypes to (value, index) tuples
+fn from_pairs<T: MpiLocReducible>(tuples: &mut [(T, usize)], pairs: &[MpiPair<T>]) {
+    for (tuple, pair) in tuples.iter_mut().zip(pairs) {
+        *tuple = (pair.value, pair.index as usize);
+    }
+}
+
+/// Splits an array packed in sequence into its parts
+fn split_by_offsets<T: MpiData>(data: &[T], offsets: &[usize]) -> Vec<Vec<T>> {
+    offsets.windows(2).map(|w| data[w[0]..w[1]].to_vec()).collect()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
-    use super::{mpi_finalize, mpi_init, Communicator};
+    use super::{from_mpi_bools, from_u32s, mpi_finalize, mpi_init, Communicator};
+    use crate::mpi_data::MpiBool;
 
     #[test]
     fn essential_features_work() {
@@ -1547,4 +2395,17 @@ mod tests {
         assert_eq!(comm.size().unwrap(), 1);
         mpi_finalize().unwrap();
     }
+
+    #[test]
+    fn bools_and_chars_conversion_works() {
+        let mut flags = [false; 3];
+        from_mpi_bools(&mut flags, &[MpiBool(1), MpiBool(0), MpiBool(2)]);
+        assert_eq!(flags, [true, false, true]);
+
+        let mut chars = [' '; 2];
+        from_u32s(&mut chars, &['π' as u32, '😊' as u32]).unwrap();
+        assert_eq!(chars, ['π', '😊']);
+        assert_eq!(from_u32s(&mut chars, &[0xD800, 0]).err(), Some("the received data contains an invalid char"));
+        assert_eq!(from_u32s(&mut chars, &[0x110000, 0]).err(), Some("the received data contains an invalid char"));
+    }
 }
@@ -1,10 +1,30 @@
 /// Defines a type alias for the error type as a static string
 pub type StrError = &'static str;
 
+mod attached_buffer;
 mod constants;
 mod conversion;
+mod datatype;
 mod enums;
+mod group;
 mod interface_mpi;
+mod mpi_data;
+#[cfg(feature = "serde")]
+mod object;
+mod request;
+mod status;
+mod user_op;
+pub use crate::attached_buffer::*;
 pub use crate::conversion::*;
+pub use crate::datatype::*;
 pub use crate::enums::*;
+pub use crate::group::*;
 pub use crate::interface_mpi::*;
+pub use crate::mpi_data::*;
+pub use crate::request::*;
+pub use crate::status::*;
+pub use crate::user_op::*;
+
+/// Implements [MpiData] for a `#[repr(C)]` struct (requires the `derive` feature)
+#[cfg(feature = "derive")]
+pub use msgpass_derive::Equivalence;
@@ -0,0 +1,269 @@
+use crate::enums::*;
+use num_complex::{Complex32, Complex64};
+
+/// Defines the types that can be communicated by MPI
+///
+/// This trait carries the index of the corresponding MPI datatype in the C code,
+/// thus generic code can communicate arrays without matching on the type.
+///
+/// **Note:** `bool` and `char` do not implement this trait because a message from a buffer of another
+/// type (e.g., `u8` or `u32`) could carry invalid bit patterns for them. Use the `*_bools` and `*_chars`
+/// functions of [crate::Communicator] instead, which receive into `u8` and `u32` buffers and convert.
+///
+/// # Safety
+///
+/// The memory layout of the type must match the MPI datatype given by `type_index`. Moreover, every
+/// bit pattern (including all-zero) must be a valid value of the type, because received bytes are
+/// reinterpreted as the type and some functions zero-fill the output (e.g., `exscan`).
+pub unsafe trait MpiData: Copy {
+    /// Returns the index of the corresponding MPI datatype (used by the C code)
+    #[doc(hidden)]
+    fn type_index() -> i32;
+}
+
+/// Defines the types that can be combined by reduce-like functions
+///
+/// The associated `Op` holds the enum with the operators allowed for the type.
+pub trait MpiReducible: MpiData {
+    /// Specifies the MPI operator enum (e.g., [MpiOpInt] or [MpiOpReal])
+    type Op: MpiOp;
+}
+
+/// Defines the types that can be combined by MINLOC/MAXLOC reductions (see [MpiOpLoc])
+///
+/// The values are paired with an index (e.g., the rank) using the corresponding MPI pair datatype.
+///
+/// # Safety
+///
+/// The memory layout of the C struct `{ value: Self, index: i32 }` must match the MPI datatype given by `pair_type_index`.
+pub unsafe trait MpiLocReducible: Copy {
+    /// Returns the index of the corresponding MPI pair datatype (used by the C code)
+    #[doc(hidden)]
+    fn pair_type_index() -> i32;
+}
+
+/// Holds a boolean with the memory layout of the MPI_C_BOOL datatype (any non-zero byte is true)
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub(crate) struct MpiBool(pub(crate) u8);
+
+/// Holds a (value, index) pair with the memory layout of the MPI pair datatypes
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct MpiPair<T> {
+    pub(crate) value: T,
+    pub(crate) index: i32,
+}
+
+unsafe impl MpiData for i32 {
+    fn type_index() -> i32 {
+        MpiType::I32.n()
+    }
+}
+
+unsafe impl MpiData for i64 {
+    fn type_index() -> i32 {
+        MpiType::I64.n()
+    }
+}
+
+unsafe impl MpiData for u32 {
+    fn type_index() -> i32 {
+        MpiType::U32.n()
+    }
+}
+
+unsafe impl MpiData for u64 {
+    fn type_index() -> i32 {
+        MpiType::U64.n()
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe impl MpiData for usize {
+    fn type_index() -> i32 {
+        MpiType::U32.n()
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+unsafe impl MpiData for usize {
+    fn type_index() -> i32 {
+        MpiType::U64.n()
+    }
+}
+
+unsafe impl MpiData for f32 {
+    fn type_index() -> i32 {
+        MpiType::F32.n()
+    }
+}
+
+unsafe impl MpiData for f64 {
+    fn type_index() -> i32 {
+        MpiType::F64.n()
+    }
+}
+
+unsafe impl MpiData for Complex32 {
+    fn type_index() -> i32 {
+        MpiType::C32.n()
+    }
+}
+
+unsafe impl MpiData for Complex64 {
+    fn type_index() -> i32 {
+        MpiType::C64.n()
+    }
+}
+
+unsafe impl MpiData for u8 {
+    fn type_index() -> i32 {
+        MpiType::BYT.n()
+    }
+}
+
+unsafe impl MpiData for i8 {
+    fn type_index() -> i32 {
+        MpiType::I8.n()
+    }
+}
+
+unsafe impl MpiData for i16 {
+    fn type_index() -> i32 {
+        MpiType::I16.n()
+    }
+}
+
+unsafe impl MpiData for u16 {
+    fn type_index() -> i32 {
+        MpiType::U16.n()
+    }
+}
+
+unsafe impl MpiData for MpiBool {
+    fn type_index() -> i32 {
+        MpiType::Bool.n()
+    }
+}
+
+impl MpiReducible for i32 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for i64 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for u32 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for u64 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for usize {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for f32 {
+    type Op = MpiOpReal;
+}
+
+impl MpiReducible for f64 {
+    type Op = MpiOpReal;
+}
+
+impl MpiReducible for Complex32 {
+    type Op = MpiOpComplex;
+}
+
+impl MpiReducible for Complex64 {
+    type Op = MpiOpComplex;
+}
+
+impl MpiReducible for u8 {
+    type Op = MpiOpByte;
+}
+
+impl MpiReducible for i8 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for i16 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for u16 {
+    type Op = MpiOpInt;
+}
+
+impl MpiReducible for MpiBool {
+    type Op = MpiOpBool;
+}
+
+unsafe impl MpiLocReducible for f32 {
+    fn pair_type_index() -> i32 {
+        MpiType::F32Int.n()
+    }
+}
+
+unsafe impl MpiLocReducible for f64 {
+    fn pair_type_index() -> i32 {
+        MpiType::F64Int.n()
+    }
+}
+
+unsafe impl MpiLocReducible for i32 {
+    fn pair_type_index() -> i32 {
+        MpiType::I32Int.n()
+    }
+}
+
+#[cfg(all(target_pointer_width = "64", not(windows)))]
+unsafe impl MpiLocReducible for i64 {
+    fn pair_type_index() -> i32 {
+        MpiType::LongInt.n() // c_long is i64
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{MpiBool, MpiData, MpiLocReducible, MpiPair};
+    use num_complex::{Complex32, Complex64};
+
+    #[test]
+    fn type_index_works() {
+        assert_eq!(i32::type_index(), 0);
+        assert_eq!(i64::type_index(), 1);
+        assert_eq!(u32::type_index(), 2);
+        assert_eq!(u64::type_index(), 3);
+        assert_eq!(f32::type_index(), 4);
+        assert_eq!(f64::type_index(), 5);
+        assert_eq!(Complex32::type_index(), 6);
+        assert_eq!(Complex64::type_index(), 7);
+        assert_eq!(u8::type_index(), 8);
+        assert_eq!(i8::type_index(), 13);
+        assert_eq!(i16::type_index(), 14);
+        assert_eq!(u16::type_index(), 15);
+        assert_eq!(MpiBool::type_index(), 16);
+        if cfg!(target_pointer_width = "64") {
+            assert_eq!(usize::type_index(), u64::type_index());
+        } else {
+            assert_eq!(usize::type_index(), u32::type_index());
+        }
+    }
+
+    #[test]
+    fn pair_type_index_works() {
+        assert_eq!(f32::pair_type_index(), 9);
+        assert_eq!(f64::pair_type_index(), 10);
+        assert_eq!(i32::pair_type_index(), 11);
+        assert_eq!(std::mem::size_of::<MpiPair<f32>>(), 8);
+        assert_eq!(std::mem::size_of::<MpiPair<f64>>(), 16);
+        assert_eq!(std::mem::size_of::<MpiPair<i32>>(), 8);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::interface_mpi::Communicator;
+use crate::status::Status;
+use crate::StrError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Serializes an object to an array of bytes
+fn to_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>, StrError> {
+    bincode::serialize(value).map_err(|_| "cannot serialize the object")
+}
+
+/// Deserializes an object from an array of bytes
+fn from_bytes<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, StrError> {
+    bincode::deserialize(bytes).map_err(|_| "cannot deserialize the object")
+}
+
+/// Implements the communication of objects (any type implementing serde's traits)
+///
+/// The objects are serialized to arrays of bytes; thus, no size limit applies (e.g., strings
+/// are not truncated). These functions require the `serde` feature.
+impl Communicator {
+    /// Sends an object (serialized) to another process
+    ///
+    /// `value` -- The object to be sent
+    /// `to_rank` -- Rank of the destination process
+    /// `tag` -- Tag of the message
+    pub fn send_object<T: Serialize>(&mut self, value: &T, to_rank: usize, tag: i32) -> Result<(), StrError> {
+        let bytes = to_bytes(value)?;
+        self.send(&bytes, to_rank, tag)
+    }
+
+    /// Receives an object (serialized) sent by another process
+    ///
+    /// The message is probed first; thus, the object may have any size.
+    ///
+    /// `from_rank` -- Rank from where the data is sent (a negative value corresponds to MPI_ANY_SOURCE)
+    /// `tag` -- Tag of the message (a negative value corresponds to MPI_ANY_TAG)
+    ///
+    /// Returns the object and the status with `count` given in number of bytes.
+    pub fn receive_object<T: DeserializeOwned>(&mut self, from_rank: i32, tag: i32) -> Result<(T, Status), StrError> {
+        let (bytes, status) = self.receive_vec::<u8>(from_rank, tag)?;
+        Ok((from_bytes(&bytes)?, status))
+    }
+
+    /// Broadcasts an object (serialized) from sender to all other processes in the group
+    ///
+    /// The number of bytes is broadcast first; thus, the object may have any size.
+    ///
+    /// `value` -- The object to be sent (sender) or overwritten (other processes)
+    pub fn broadcast_object<T: Serialize + DeserializeOwned>(&mut self, sender: usize, value: &mut T) -> Result<(), StrError> {
+        if self.rank()? == sender {
+            let mut bytes = to_bytes(value)?;
+            self.broadcast(sender, &mut [bytes.len()])?;
+            self.broadcast(sender, &mut bytes)?;
+        } else {
+            let mut len = [0_usize];
+            self.broadcast(sender, &mut len)?;
+            let mut bytes = vec![0_u8; len[0]];
+            self.broadcast(sender, &mut bytes)?;
+            *value = from_bytes(&bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Gathers objects (serialized) from all processes to the root process
+    ///
+    /// Returns the objects received from each process on the root process (None on the other processes).
+    pub fn gather_objects<T: Serialize + DeserializeOwned>(&mut self, root: usize, value: &T) -> Result<Option<Vec<T>>, StrError> {
+        let bytes = to_bytes(value)?;
+        let (data, offsets) = self.gather_flat(root, &bytes)?;
+        if offsets.is_empty() {
+            return Ok(None);
+        }
+        let values = offsets.windows(2).map(|w| from_bytes(&data[w[0]..w[1]])).collect::<Result<_, _>>()?;
+        Ok(Some(values))
+    }
+
+    /// Gathers objects (serialized) from all processes and distributes the result to all processes
+    ///
+    /// Returns the objects received from each process.
+    pub fn allgather_objects<T: Serialize + DeserializeOwned>(&mut self, value: &T) -> Result<Vec<T>, StrError> {
+        let bytes = to_bytes(value)?;
+        let (data, offsets) = self.allgather_flat(&bytes)?;
+        offsets.windows(2).map(|w| from_bytes(&data[w[0]..w[1]])).collect()
+    }
+
+    /// Scatters objects (serialized) from the root process to all processes
+    ///
+    /// `orig` -- The objects to be sent to each process (root only; must be None on the other processes)
+    ///
+    /// Returns the object received by this process.
+    pub fn scatter_objects<T: Serialize + DeserializeOwned>(&mut self, root: usize, orig: Option<&[T]>) -> Result<T, StrError> {
+        let bytes = match orig {
+            Some(o) => {
+                let all = o.iter().map(to_bytes).collect::<Result<Vec<_>, _>>()?;
+                self.scatter_vec(root, Some(all.as_slice()))?
+            }
+            None => self.scatter_vec(root, None)?,
+        };
+        from_bytes(&bytes)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{from_bytes, to_bytes};
+    use std::collections::HashMap;
+
+    #[test]
+    fn to_and_from_bytes_work() {
+        let mut map = HashMap::new();
+        map.insert("Hello World 😊".to_string(), vec![1.0, 2.0]);
+        map.insert("empty".to_string(), Vec::new());
+        let bytes = to_bytes(&map).unwrap();
+        let back: HashMap<String, Vec<f64>> = from_bytes(&bytes).unwrap();
+        assert_eq!(back, map);
+
+        let value: Option<(u8, String)> = Some((3, "three".to_string()));
+        let back: Option<(u8, String)> = from_bytes(&to_bytes(&value).unwrap()).unwrap();
+        assert_eq!(back, value);
+
+        let res: Result<String, _> = from_bytes(&[255, 0]);
+        assert_eq!(res.err(), Some("cannot deserialize the object"));
+    }
+}
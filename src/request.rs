@@ -0,0 +1,416 @@
+use crate::constants::*;
+use crate::conversion::to_i32;
+use crate::mpi_data::MpiData;
+use crate::status::{ExtStatus, Status};
+use crate::StrError;
+use std::marker::PhantomData;
+
+#[repr(C)]
+pub(crate) struct ExtRequest {
+    data: [u8; 0],
+    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+#[repr(C)]
+pub(crate) struct ExtRequestSet {
+    data: [u8; 0],
+    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+extern "C" {
+    fn req_drop(req: *mut ExtRequest);
+    fn req_new() -> *mut ExtRequest;
+    fn req_wait(req: *mut ExtRequest, status: *mut ExtStatus) -> i32;
+    fn req_test(req: *mut ExtRequest, flag: *mut i32, status: *mut ExtStatus) -> i32;
+    fn preq_drop(req: *mut ExtRequest);
+    fn preq_start(req: *mut ExtRequest) -> i32;
+    fn preq_start_all(n: i32, reqs: *mut *mut ExtRequest) -> i32;
+    fn reqset_drop(set: *mut ExtRequestSet);
+    fn reqset_new() -> *mut ExtRequestSet;
+    fn reqset_push(set: *mut ExtRequestSet, req: *mut ExtRequest) -> i32;
+    fn reqset_wait_all(set: *mut ExtRequestSet, statuses: *mut ExtStatus) -> i32;
+    fn reqset_wait_any(set: *mut ExtRequestSet, index: *mut i32, status: *mut ExtStatus) -> i32;
+    fn reqset_wait_some(set: *mut ExtRequestSet, n_completed: *mut i32, indices: *mut i32, statuses: *mut ExtStatus) -> i32;
+    fn reqset_test_all(set: *mut ExtRequestSet, flag: *mut i32, statuses: *mut ExtStatus) -> i32;
+}
+
+/// Holds a non-blocking communication request (wrapping the C data)
+///
+/// The request borrows the communication buffer for its lifetime; thus, the buffer cannot
+/// be accessed (or modified) until the operation completes. The request is waited for
+/// when dropped if it has not been completed by [Request::wait] or [Request::test] yet.
+///
+/// **Warning:** Leaking a request (e.g., via `std::mem::forget`) would release the buffer while
+/// MPI may still access it; thus, the functions creating requests are `unsafe` (see
+/// [crate::Communicator::isend] and [crate::Communicator::irecv]).
+#[must_use = "the request is waited for when dropped; call wait() or test() instead"]
+pub struct Request<'a> {
+    pub(crate) handle: *mut ExtRequest,
+    marker: PhantomData<&'a mut ()>,
+}
+
+impl<'a> Drop for Request<'a> {
+    /// Waits for the pending operation and deallocates the C memory
+    fn drop(&mut self) {
+        unsafe {
+            req_drop(self.handle);
+        }
+    }
+}
+
+impl<'a> Request<'a> {
+    /// Allocates a new (inactive) instance
+    pub(crate) fn new() -> Result<Self, StrError> {
+        unsafe {
+            let ext_req = req_new();
+            if ext_req.is_null() {
+                return Err("MPI failed to allocate the request");
+            }
+            Ok(Request { handle: ext_req, marker: PhantomData })
+        }
+    }
+
+    /// Waits for the operation to complete
+    pub fn wait(self) -> Result<Status, StrError> {
+        let mut ext_status = ExtStatus::default();
+        unsafe {
+            let status = req_wait(self.handle, &mut ext_status);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to wait for the request");
+            }
+        }
+        Ok(Status::from(ext_status))
+    }
+
+    /// Tests whether the operation has completed or not
+    ///
+    /// Returns the status if the operation has completed; otherwise, returns None.
+    pub fn test(&mut self) -> Result<Option<Status>, StrError> {
+        let mut flag: i32 = 0;
+        let mut ext_status = ExtStatus::default();
+        unsafe {
+            let status = req_test(self.handle, &mut flag, &mut ext_status);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to test the request");
+            }
+        }
+        if flag == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(Status::from(ext_status)))
+        }
+    }
+}
+
+mod private {
+    use super::ExtRequest;
+
+    /// Holds the C request of a startable request
+    pub struct Handle(pub(crate) *mut ExtRequest);
+
+    /// Gives access to the C data of the startable requests (prevents implementations outside this crate)
+    pub trait Sealed {
+        fn handle(&self) -> Handle;
+        fn set_active(&mut self);
+    }
+}
+
+/// Defines requests that can be started, regardless of the type of their buffer (see [start_all])
+pub trait Startable: private::Sealed {
+    /// Returns true if the communication has been started and not completed yet
+    fn is_active(&self) -> bool;
+}
+
+/// Starts the communication of all (persistent) requests
+///
+/// The requests may be bound to buffers of different types; e.g.:
+///
+/// ```ignore
+/// start_all(&mut [&mut send_f64, &mut send_i32, &mut recv_f64])?;
+/// ```
+pub fn start_all(requests: &mut [&mut dyn Startable]) -> Result<(), StrError> {
+    if requests.iter().any(|r| r.is_active()) {
+        return Err("all persistent requests must be inactive");
+    }
+    let mut handles: Vec<*mut ExtRequest> = requests.iter().map(|r| r.handle().0).collect();
+    unsafe {
+        let status = preq_start_all(to_i32(handles.len()), handles.as_mut_ptr());
+        if status != C_MPI_SUCCESS {
+            return Err("MPI failed to start all persistent requests");
+        }
+    }
+    for request in requests.iter_mut() {
+        request.set_active();
+    }
+    Ok(())
+}
+
+/// Holds a persistent communication request bound to a buffer (wrapping the C data)
+///
+/// A persistent request is created once (see [crate::Communicator::send_init] and
+/// [crate::Communicator::recv_init]) and then started many times, avoiding the overhead of
+/// posting the same communication repeatedly. The request owns a mutable borrow of the buffer,
+/// which can be accessed via [PersistentRequest::data] and [PersistentRequest::data_mut]
+/// while the request is inactive (i.e., not started or already completed).
+///
+/// The request is waited for (if active) and freed when dropped.
+///
+/// **Warning:** Leaking a persistent request (e.g., via `std::mem::forget`) would release the buffer
+/// while MPI may still access it; thus, the functions creating persistent requests are `unsafe` (see
+/// [crate::Communicator::send_init] and [crate::Communicator::recv_init]).
+pub struct PersistentRequest<'a, T: MpiData> {
+    handle: *mut ExtRequest,
+    data: *mut T,
+    len: usize,
+    active: bool,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: MpiData> Drop for PersistentRequest<'a, T> {
+    /// Waits for the active operation (if any), frees the request, and deallocates the C memory
+    fn drop(&mut self) {
+        unsafe {
+            preq_drop(self.handle);
+        }
+    }
+}
+
+impl<'a, T: MpiData> PersistentRequest<'a, T> {
+    /// Allocates a new (inactive) instance bound to a buffer
+    pub(crate) fn new(data: &'a mut [T]) -> Result<Self, StrError> {
+        unsafe {
+            let ext_req = req_new();
+            if ext_req.is_null() {
+                return Err("MPI failed to allocate the persistent request");
+            }
+            Ok(PersistentRequest {
+                handle: ext_req,
+                data: data.as_mut_ptr(),
+                len: data.len(),
+                active: false,
+                marker: PhantomData,
+            })
+        }
+    }
+
+    /// Returns the C request
+    pub(crate) fn ext(&self) -> *mut ExtRequest {
+        self.handle
+    }
+
+    /// Returns the pointer to the buffer and its length
+    pub(crate) fn buffer(&self) -> (*mut T, i32) {
+        (self.data, to_i32(self.len))
+    }
+
+    /// Starts the communication
+    pub fn start(&mut self) -> Result<(), StrError> {
+        if self.active {
+            return Err("the persistent request is already active");
+        }
+        unsafe {
+            let status = preq_start(self.handle);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to start the persistent request");
+            }
+        }
+        self.active = true;
+        Ok(())
+    }
+
+    /// Waits for the communication to complete (the request becomes inactive)
+    pub fn wait(&mut self) -> Result<Status, StrError> {
+        let mut ext_status = ExtStatus::default();
+        unsafe {
+            let status = req_wait(self.handle, &mut ext_status);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to wait for the persistent request");
+            }
+        }
+        self.active = false;
+        Ok(Status::from(ext_status))
+    }
+
+    /// Tests whether the communication has completed or not
+    ///
+    /// Returns the status if the communication has completed (the request becomes inactive);
+    /// otherwise, returns None.
+    pub fn test(&mut self) -> Result<Option<Status>, StrError> {
+        let mut flag: i32 = 0;
+        let mut ext_status = ExtStatus::default();
+        unsafe {
+            let status = req_test(self.handle, &mut flag, &mut ext_status);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to test the persistent request");
+            }
+        }
+        if flag == 0 {
+            Ok(None)
+        } else {
+            self.active = false;
+            Ok(Some(Status::from(ext_status)))
+        }
+    }
+
+    /// Returns true if the communication has been started and not completed yet
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Returns access to the buffer (the request must be inactive)
+    pub fn data(&self) -> Result<&[T], StrError> {
+        if self.active {
+            return Err("cannot access the buffer of an active persistent request");
+        }
+        unsafe { Ok(std::slice::from_raw_parts(self.data, self.len)) }
+    }
+
+    /// Returns mutable access to the buffer (the request must be inactive)
+    pub fn data_mut(&mut self) -> Result<&mut [T], StrError> {
+        if self.active {
+            return Err("cannot access the buffer of an active persistent request");
+        }
+        unsafe { Ok(std::slice::from_raw_parts_mut(self.data, self.len)) }
+    }
+}
+
+impl<'a, T: MpiData> private::Sealed for PersistentRequest<'a, T> {
+    fn handle(&self) -> private::Handle {
+        private::Handle(self.handle)
+    }
+
+    fn set_active(&mut self) {
+        self.active = true;
+    }
+}
+
+impl<'a, T: MpiData> Startable for PersistentRequest<'a, T> {
+    fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+/// Holds a collection of non-blocking communication requests (wrapping the C data)
+///
+/// The C code owns the array of requests; thus, the requests pushed into the set become
+/// part of the set and are identified by their index (the order of insertion).
+/// The pending requests are waited for when the set is dropped.
+#[must_use = "the requests are waited for when the set is dropped"]
+pub struct RequestSet<'a> {
+    handle: *mut ExtRequestSet,
+    size: usize,
+    marker: PhantomData<&'a mut ()>,
+}
+
+impl<'a> Drop for RequestSet<'a> {
+    /// Waits for the pending operations and deallocates the C memory
+    fn drop(&mut self) {
+        unsafe {
+            reqset_drop(self.handle);
+        }
+    }
+}
+
+impl<'a> RequestSet<'a> {
+    /// Allocates a new (empty) instance
+    pub fn new() -> Result<Self, StrError> {
+        unsafe {
+            let ext_set = reqset_new();
+            if ext_set.is_null() {
+                return Err("MPI failed to allocate the request set");
+            }
+            Ok(RequestSet { handle: ext_set, size: 0, marker: PhantomData })
+        }
+    }
+
+    /// Moves a request into the set and returns its index
+    pub fn push(&mut self, request: Request<'a>) -> Result<usize, StrError> {
+        unsafe {
+            let status = reqset_push(self.handle, request.handle);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to push the request into the set");
+            }
+        }
+        self.size += 1;
+        Ok(self.size - 1)
+    }
+
+    /// Returns the number of requests in the set
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns true if the set has no requests
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Waits for all requests to complete
+    ///
+    /// Returns the status of each request (in the order of insertion).
+    pub fn wait_all(&mut self) -> Result<Vec<Status>, StrError> {
+        let mut ext_statuses = vec![ExtStatus::default(); self.size];
+        unsafe {
+            let status = reqset_wait_all(self.handle, ext_statuses.as_mut_ptr());
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to wait for all requests");
+            }
+        }
+        Ok(ext_statuses.into_iter().map(Status::from).collect())
+    }
+
+    /// Waits for any request to complete
+    ///
+    /// Returns the index and status of the completed request or None if there are no active requests.
+    pub fn wait_any(&mut self) -> Result<Option<(usize, Status)>, StrError> {
+        let mut index: i32 = 0;
+        let mut ext_status = ExtStatus::default();
+        unsafe {
+            let status = reqset_wait_any(self.handle, &mut index, &mut ext_status);
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to wait for any request");
+            }
+        }
+        if index < 0 {
+            Ok(None)
+        } else {
+            Ok(Some((index as usize, Status::from(ext_status))))
+        }
+    }
+
+    /// Waits for at least one request to complete
+    ///
+    /// Returns the indices and statuses of the completed requests (empty if there are no active requests).
+    pub fn wait_some(&mut self) -> Result<Vec<(usize, Status)>, StrError> {
+        let mut n_completed: i32 = 0;
+        let mut indices = vec![0_i32; self.size];
+        let mut ext_statuses = vec![ExtStatus::default(); self.size];
+        unsafe {
+            let status = reqset_wait_some(self.handle, &mut n_completed, indices.as_mut_ptr(), ext_statuses.as_mut_ptr());
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to wait for some requests");
+            }
+        }
+        let n = if n_completed < 0 { 0 } else { n_completed as usize };
+        Ok((0..n).map(|i| (indices[i] as usize, Status::from(ext_statuses[i]))).collect())
+    }
+
+    /// Tests whether all requests have completed or not
+    ///
+    /// Returns the status of each request if all have completed; otherwise, returns None.
+    pub fn test_all(&mut self) -> Result<Option<Vec<Status>>, StrError> {
+        let mut flag: i32 = 0;
+        let mut ext_statuses = vec![ExtStatus::default(); self.size];
+        unsafe {
+            let status = reqset_test_all(self.handle, &mut flag, ext_statuses.as_mut_ptr());
+            if status != C_MPI_SUCCESS {
+                return Err("MPI failed to test all requests");
+            }
+        }
+        if flag == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(ext_statuses.into_iter().map(Status::from).collect()))
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/// Holds the status data written by the C code (mirrors the C struct)
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ExtStatus {
+    pub(crate) source: i32,
+    pub(crate) tag: i32,
+    pub(crate) error: i32,
+    pub(crate) count: i32, // negative means undefined
+    pub(crate) cancelled: i32,
+}
+
+/// Holds information about a completed communication
+///
+/// **Note:** The `source`, `tag`, and `count` fields are only meaningful for receive (or probe) operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Status {
+    /// Rank of the process that sent the message
+    pub source: i32,
+
+    /// Tag of the message
+    pub tag: i32,
+
+    /// Error code (MPI_SUCCESS = 0)
+    pub error: i32,
+
+    /// Number of received elements (None if undefined; e.g., the message size is not a multiple of the datatype size)
+    pub count: Option<usize>,
+
+    /// Indicates whether the operation has been cancelled or not
+    pub cancelled: bool,
+}
+
+impl From<ExtStatus> for Status {
+    fn from(ext: ExtStatus) -> Self {
+        Status {
+            source: ext.source,
+            tag: ext.tag,
+            error: ext.error,
+            count: if ext.count < 0 { None } else { Some(ext.count as usize) },
+            cancelled: ext.cancelled != 0,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ExtStatus, Status};
+
+    #[test]
+    fn from_ext_status_works() {
+        let ext = ExtStatus { source: 1, tag: 2, error: 0, count: 3, cancelled: 0 };
+        let status = Status::from(ext);
+        assert_eq!(
+            status,
+            Status {
+                source: 1,
+                tag: 2,
+                error: 0,
+                count: Some(3),
+                cancelled: false
+            }
+        );
+        let ext = ExtStatus { source: 1, tag: 2, error: 0, count: -1, cancelled: 1 };
+        let status = Status::from(ext);
+        assert_eq!(status.count, None);
+        assert!(status.cancelled);
+    }
+}
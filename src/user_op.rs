@@ -0,0 +1,125 @@
+use crate::mpi_data::MpiData;
+use crate::StrError;
+use std::ffi::c_void;
+
+#[repr(C)]
+pub(crate) struct ExtUserOp {
+    data: [u8; 0],
+    marker: core::marker::PhantomData<(*mut u8, core::marker::PhantomPinned)>,
+}
+
+/// Defines the signature of the MPI user function
+type UserFunction = extern "C" fn(invec: *mut c_void, inoutvec: *mut c_void, len: *mut i32, datatype: *mut c_void);
+
+extern "C" {
+    fn user_op_drop(op: *mut ExtUserOp);
+    fn user_op_new(function: UserFunction, commute: i32) -> *mut ExtUserOp;
+    fn user_op_context(datatype: *mut c_void) -> *const c_void;
+}
+
+/// Defines the Rust function called by the reduction
+type BoxedFunction<T> = Box<dyn Fn(&[T], &mut [T]) + Sync>;
+
+/// Calls the Rust function of the operation being executed
+///
+/// The function is attached to the datatype given by MPI (see [UserOp::context]).
+extern "C" fn trampoline<T: MpiData>(invec: *mut c_void, inoutvec: *mut c_void, len: *mut i32, datatype: *mut c_void) {
+    unsafe {
+        let function = user_op_context(datatype) as *const BoxedFunction<T>;
+        if function.is_null() {
+            // unreachable: the operation is only used with datatypes carrying the function (abort because a panic cannot unwind through MPI)
+            std::process::abort();
+        }
+        apply(&*function, invec, inoutvec, len);
+    }
+}
+
+/// Calls the Rust function with the arrays given by MPI
+unsafe fn apply<T: MpiData>(function: &BoxedFunction<T>, invec: *mut c_void, inoutvec: *mut c_void, len: *mut i32) {
+    unsafe {
+        let n = *len as usize;
+        let a = std::slice::from_raw_parts(invec as *const T, n);
+        let b = std::slice::from_raw_parts_mut(inoutvec as *mut T, n);
+        function(a, b);
+    }
+}
+
+/// Holds a user-defined reduction operation (wrapping the C data)
+///
+/// The operation is given by a Rust function `f(a, b)` that must compute, element-wise,
+/// `b[i] = a[i] ∘ b[i]`, where `∘` is an associative operation and `a` comes from the
+/// lower ranks. The operation is registered with MPI on allocation and freed when dropped.
+///
+/// Use it with [crate::Communicator::reduce_with], [crate::Communicator::allreduce_with],
+/// and [crate::Communicator::scan_with].
+///
+/// **Note:** The function must not panic because the panic cannot unwind through MPI (the process is aborted).
+///
+/// **Note:** The function is passed to MPI as an attribute of (a duplicate of) the datatype; thus, MPI may
+/// call it from any thread (e.g., a progress thread) while the reduction is running. Hence, it must be `Sync`.
+pub struct UserOp<T: MpiData> {
+    handle: *mut ExtUserOp,
+    function: BoxedFunction<T>,
+}
+
+impl<T: MpiData> Drop for UserOp<T> {
+    /// Frees the operation and deallocates the C memory
+    fn drop(&mut self) {
+        unsafe {
+            user_op_drop(self.handle);
+        }
+    }
+}
+
+impl<T: MpiData> UserOp<T> {
+    /// Registers a new user-defined reduction operation
+    ///
+    /// `function` -- The function `f(a, b)` computing `b[i] = a[i] ∘ b[i]`
+    /// `commute` -- Indicates that the operation is commutative (allowing MPI to reorder the evaluation)
+    pub fn new<F>(function: F, commute: bool) -> Result<Self, StrError>
+    where
+        F: Fn(&[T], &mut [T]) + Sync + 'static,
+    {
+        unsafe {
+            let ext_op = user_op_new(trampoline::<T>, if commute { 1 } else { 0 });
+            if ext_op.is_null() {
+                return Err("MPI failed to create the user-defined operation");
+            }
+            Ok(UserOp { handle: ext_op, function: Box::new(function) })
+        }
+    }
+
+    /// Returns the C operation
+    pub(crate) fn ext(&self) -> *mut ExtUserOp {
+        self.handle
+    }
+
+    /// Returns the context given to MPI along with the operation (i.e., the Rust function)
+    pub(crate) fn context(&self) -> *mut c_void {
+        &self.function as *const BoxedFunction<T> as *mut c_void
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, BoxedFunction};
+    use std::ffi::c_void;
+
+    #[test]
+    fn apply_works() {
+        let function: BoxedFunction<i32> = Box::new(|a, b| {
+            for i in 0..a.len() {
+                b[i] = i32::max(a[i].abs(), b[i].abs());
+            }
+        });
+        let a = [-3, 1, 2];
+        let mut b = [2, -5, 1];
+        let mut len = 3;
+        unsafe {
+            apply(&function, a.as_ptr() as *mut c_void, b.as_mut_ptr() as *mut c_void, &mut len);
+        }
+        assert_eq!(b, [3, 5, 2]);
+    }
+}